@@ -0,0 +1,242 @@
+//! Derive macro turning an error enum into a `krabby_details::ProblemDetails`.
+//!
+//! See the `derive` feature of `krabby_details` for how to use it.
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+    Data, DeriveInput, Fields, LitInt, LitStr, Variant, parse_macro_input, spanned::Spanned,
+};
+
+/// Implements `From<T> for ProblemDetails<DynamicExtensions>` for an error
+/// enum, and `IntoResponse` with the `axum` feature.
+///
+/// Each variant is described by a `#[problem(...)]` attribute with these
+/// optional keys:
+///
+/// - `status`: the 4xx or 5xx status code, `500` by default.
+/// - `type`: the problem type URI, `about:blank` by default.
+/// - `title`: the title, the canonical reason of `status` by default.
+/// - `detail`: the detail.
+///
+/// `title` and `detail` are format strings that can capture the fields of
+/// struct variants, e.g. `detail = "No user with id {id}"`.
+///
+/// Fields marked with `#[problem(extension)]` are added as extension
+/// members named after the field, or after the given name with
+/// `#[problem(extension = "name")]`. They must implement `serde::Serialize`.
+#[proc_macro_derive(Problem, attributes(problem))]
+pub fn derive_problem(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream> {
+    let Data::Enum(data) = &input.data else {
+        return Err(syn::Error::new(
+            input.ident.span(),
+            "Problem can only be derived for enums",
+        ));
+    };
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let arms = data
+        .variants
+        .iter()
+        .map(|variant| expand_variant(ident, variant))
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let into_response = cfg!(feature = "axum").then(|| {
+        quote! {
+            impl #impl_generics ::krabby_details::__private::axum_core::response::IntoResponse
+                for #ident #ty_generics
+                #where_clause
+            {
+                fn into_response(self) -> ::krabby_details::__private::axum_core::response::Response {
+                    ::krabby_details::__private::axum_core::response::IntoResponse::into_response(
+                        ::krabby_details::ProblemDetails::<::krabby_details::DynamicExtensions>::from(self),
+                    )
+                }
+            }
+        }
+    });
+
+    Ok(quote! {
+        impl #impl_generics ::core::convert::From<#ident #ty_generics>
+            for ::krabby_details::ProblemDetails<::krabby_details::DynamicExtensions>
+            #where_clause
+        {
+            #[allow(unused_variables)]
+            fn from(error: #ident #ty_generics) -> Self {
+                match error {
+                    #(#arms)*
+                }
+            }
+        }
+
+        #into_response
+    })
+}
+
+/// The `#[problem(...)]` attribute of a variant.
+#[derive(Default)]
+struct VariantAttrs {
+    status: Option<u16>,
+    type_: Option<LitStr>,
+    title: Option<LitStr>,
+    detail: Option<LitStr>,
+}
+
+fn parse_variant_attrs(variant: &Variant) -> syn::Result<VariantAttrs> {
+    let mut attrs = VariantAttrs::default();
+
+    for attr in variant
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("problem"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("status") {
+                let lit: LitInt = meta.value()?.parse()?;
+                let status = lit.base10_parse::<u16>()?;
+                if !(400..=599).contains(&status) {
+                    return Err(syn::Error::new(
+                        lit.span(),
+                        "status must be a 4xx or 5xx status code",
+                    ));
+                }
+                attrs.status = Some(status);
+            } else if meta.path.is_ident("type") {
+                attrs.type_ = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("title") {
+                attrs.title = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("detail") {
+                attrs.detail = Some(meta.value()?.parse()?);
+            } else {
+                return Err(meta.error("expected `status`, `type`, `title` or `detail`"));
+            }
+            Ok(())
+        })?;
+    }
+
+    Ok(attrs)
+}
+
+/// The members of a problem that extensions must not redefine, like
+/// `krabby_details::RESERVED_MEMBERS`.
+const RESERVED_MEMBERS: [&str; 5] = ["type", "status", "title", "detail", "instance"];
+
+/// The extension member name of a field marked with `#[problem(extension)]`.
+fn parse_extension_name(field: &syn::Field) -> syn::Result<Option<String>> {
+    let mut name = None;
+
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("problem"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if !meta.path.is_ident("extension") {
+                return Err(meta.error("expected `extension`"));
+            }
+            let extension = match meta.value() {
+                Ok(value) => {
+                    let lit = value.parse::<LitStr>()?;
+                    (lit.value(), lit.span())
+                }
+                Err(_) => match &field.ident {
+                    Some(ident) => (ident.to_string(), ident.span()),
+                    None => {
+                        return Err(meta.error(
+                            "tuple fields need a name, e.g. `#[problem(extension = \"name\")]`",
+                        ));
+                    }
+                },
+            };
+            // Would fail every serialization under the default policy.
+            if RESERVED_MEMBERS.contains(&extension.0.as_str()) {
+                return Err(syn::Error::new(
+                    extension.1,
+                    format!(
+                        "`{}` is a standard problem member, name the extension otherwise, e.g. `#[problem(extension = \"name\")]`",
+                        extension.0
+                    ),
+                ));
+            }
+            name = Some(extension.0);
+            Ok(())
+        })?;
+    }
+
+    Ok(name)
+}
+
+/// A `title` or `detail` string, formatted when it captures fields.
+fn format_member(lit: &LitStr) -> TokenStream {
+    if lit.value().contains(['{', '}']) {
+        quote!(::std::format!(#lit))
+    } else {
+        quote!(#lit)
+    }
+}
+
+fn expand_variant(ident: &syn::Ident, variant: &Variant) -> syn::Result<TokenStream> {
+    let attrs = parse_variant_attrs(variant)?;
+    let variant_ident = &variant.ident;
+
+    let bindings = variant
+        .fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| match &field.ident {
+            Some(ident) => ident.clone(),
+            None => format_ident!("field_{}", index),
+        })
+        .collect::<Vec<_>>();
+    let pattern = match &variant.fields {
+        Fields::Named(_) => quote!(#ident::#variant_ident { #(#bindings),* }),
+        Fields::Unnamed(_) => quote!(#ident::#variant_ident(#(#bindings),*)),
+        Fields::Unit => quote!(#ident::#variant_ident),
+    };
+
+    let status = attrs.status.unwrap_or(500);
+    let mut setters = Vec::new();
+    if let Some(type_) = &attrs.type_ {
+        setters.push(quote!(problem = problem.type_(#type_);));
+    }
+    if let Some(title) = &attrs.title {
+        let title = format_member(title);
+        setters.push(quote!(problem = problem.title(#title);));
+    }
+    if let Some(detail) = &attrs.detail {
+        let detail = format_member(detail);
+        setters.push(quote!(problem = problem.detail(#detail);));
+    }
+    for (field, binding) in variant.fields.iter().zip(&bindings) {
+        if let Some(name) = parse_extension_name(field)? {
+            let name = LitStr::new(&name, field.span());
+            setters.push(quote! {
+                problem.insert_extension(
+                    #name,
+                    ::krabby_details::__private::extension_value(&#binding),
+                );
+            });
+        }
+    }
+
+    Ok(quote! {
+        #pattern => {
+            let mut problem = ::krabby_details::ProblemDetails::<
+                ::krabby_details::DynamicExtensions,
+            >::new(
+                ::krabby_details::__private::http::StatusCode::from_u16(#status)
+                    .unwrap_or(::krabby_details::__private::http::StatusCode::INTERNAL_SERVER_ERROR),
+            );
+            #(#setters)*
+            problem
+        }
+    })
+}
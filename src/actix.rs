@@ -0,0 +1,55 @@
+//! Responses for actix-web, which is built on another major version of the
+//! `http` crate than the rest of this crate.
+use actix_web::{
+    HttpRequest, HttpResponse, Responder, ResponseError, body::BoxBody,
+    http::StatusCode as ActixStatusCode,
+};
+use http::StatusCode;
+use serde::Serialize;
+
+use crate::ProblemDetails;
+
+impl<Extension> ProblemDetails<Extension>
+where
+    Extension: Serialize,
+{
+    fn actix_response(&self) -> HttpResponse {
+        let (parts, body) = self.to_http_response().into_parts();
+
+        let mut response = HttpResponse::build(actix_status(parts.status));
+        for (name, value) in &parts.headers {
+            response.append_header((name.as_str(), value.as_bytes()));
+        }
+        response.body(body)
+    }
+}
+
+fn actix_status(status: StatusCode) -> ActixStatusCode {
+    ActixStatusCode::from_u16(status.as_u16()).unwrap_or(ActixStatusCode::INTERNAL_SERVER_ERROR)
+}
+
+/// Sends the problem like its axum `IntoResponse` does.
+impl<Extension> Responder for ProblemDetails<Extension>
+where
+    Extension: Serialize,
+{
+    type Body = BoxBody;
+
+    fn respond_to(self, _: &HttpRequest) -> HttpResponse {
+        self.actix_response()
+    }
+}
+
+/// Lets handlers return the problem as their error, e.g. with `?`.
+impl<Extension> ResponseError for ProblemDetails<Extension>
+where
+    Extension: Serialize + std::fmt::Debug,
+{
+    fn status_code(&self) -> ActixStatusCode {
+        actix_status(ProblemDetails::status_code(self))
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        self.actix_response()
+    }
+}
@@ -0,0 +1,64 @@
+//! Chained setters to build a [`ProblemDetails`].
+use std::borrow::Cow;
+
+use http::{HeaderName, HeaderValue, StatusCode};
+
+use crate::{ProblemDetails, ReservedMemberPolicy};
+
+impl<Extension> ProblemDetails<Extension> {
+    /// Starts an [`ABOUT_BLANK`](crate::ABOUT_BLANK) problem without any
+    /// other member.
+    pub fn builder() -> Self {
+        Self::default()
+    }
+
+    /// Starts a problem for `status`, titled with its canonical reason.
+    pub fn new(status: StatusCode) -> Self {
+        Self {
+            status: Some(status.as_u16()),
+            title: status.canonical_reason().map(Cow::Borrowed),
+            ..Self::default()
+        }
+    }
+
+    pub fn type_(mut self, type_: impl Into<Cow<'static, str>>) -> Self {
+        self.type_ = type_.into();
+        self
+    }
+
+    pub fn status(mut self, status: StatusCode) -> Self {
+        self.status = Some(status.as_u16());
+        self
+    }
+
+    pub fn title(mut self, title: impl Into<Cow<'static, str>>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn detail(mut self, detail: impl Into<Cow<'static, str>>) -> Self {
+        self.detail = Some(detail.into());
+        self
+    }
+
+    pub fn instance(mut self, instance: impl Into<Cow<'static, str>>) -> Self {
+        self.instance = Some(instance.into());
+        self
+    }
+
+    pub fn extension(mut self, extension: Extension) -> Self {
+        self.extensions = Some(extension);
+        self
+    }
+
+    /// Appends a header to the response, keeping any previous value.
+    pub fn header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.headers.append(name, value);
+        self
+    }
+
+    pub fn reserved_members(mut self, policy: ReservedMemberPolicy) -> Self {
+        self.reserved_members = policy;
+        self
+    }
+}
@@ -0,0 +1,85 @@
+//! Parsing problems out of responses returned by other services.
+use std::{error::Error, fmt};
+
+use bytes::Bytes;
+use http::{HeaderMap, header::CONTENT_TYPE};
+use serde::de::DeserializeOwned;
+
+use crate::{ProblemDetails, response::BODY_HEADERS};
+
+impl<Extension> ProblemDetails<Extension>
+where
+    Extension: DeserializeOwned,
+{
+    /// Parses the problem carried by an `application/problem+json` response.
+    pub fn from_response(response: &http::Response<Bytes>) -> Result<Self, FromResponseError> {
+        if !is_problem_json(response.headers()) {
+            return Err(FromResponseError::ContentType);
+        }
+
+        serde_json::from_slice(response.body()).map_err(FromResponseError::Json)
+    }
+}
+
+/// Like [`ProblemDetails::from_response`], also keeping the status and the
+/// headers of the response, so the problem can be forwarded as is.
+impl<Extension> TryFrom<http::Response<Bytes>> for ProblemDetails<Extension>
+where
+    Extension: DeserializeOwned,
+{
+    type Error = FromResponseError;
+
+    fn try_from(response: http::Response<Bytes>) -> Result<Self, Self::Error> {
+        let mut problem = Self::from_response(&response)?;
+
+        let (mut parts, _) = response.into_parts();
+        for header in BODY_HEADERS {
+            parts.headers.remove(header);
+        }
+        problem.response_status = Some(parts.status);
+        problem.headers = parts.headers;
+
+        Ok(problem)
+    }
+}
+
+/// Whether the `Content-Type` is `application/problem+json`, ignoring
+/// parameters such as `charset`.
+fn is_problem_json(headers: &HeaderMap) -> bool {
+    let Some(content_type) = headers
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+    else {
+        return false;
+    };
+    let essence = content_type.split(';').next().unwrap_or_default().trim();
+
+    essence.eq_ignore_ascii_case("application/problem+json")
+}
+
+/// The reasons a response could not be parsed into a [`ProblemDetails`].
+#[derive(Debug)]
+pub enum FromResponseError {
+    /// The response is not `application/problem+json`.
+    ContentType,
+    /// The body is not a valid problem.
+    Json(serde_json::Error),
+}
+
+impl fmt::Display for FromResponseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ContentType => f.write_str("response is not application/problem+json"),
+            Self::Json(_) => f.write_str("response body is not a valid problem"),
+        }
+    }
+}
+
+impl Error for FromResponseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::ContentType => None,
+            Self::Json(error) => Some(error),
+        }
+    }
+}
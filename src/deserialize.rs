@@ -0,0 +1,78 @@
+//! Deserialization of request bodies, pinpointing the values that fail to.
+use http::StatusCode;
+use serde::de::DeserializeOwned;
+use serde_json::error::Category;
+
+use crate::{JsonPointer, ProblemDetails, Source, ValidationError, ValidationErrors};
+
+/// Deserializes a JSON body, or describes where it failed to.
+///
+/// A body that is not JSON is a `400 Bad Request`, and one that does not
+/// match `T` an `422 Unprocessable Entity` whose validation error points at
+/// the offending value.
+// The problem is meant to be returned as is from handlers, boxing it would
+// only get in the way.
+#[allow(clippy::result_large_err)]
+pub fn from_json_slice<T>(body: &[u8]) -> Result<T, ProblemDetails<ValidationErrors>>
+where
+    T: DeserializeOwned,
+{
+    let mut deserializer = serde_json::Deserializer::from_slice(body);
+    let value = serde_path_to_error::deserialize(&mut deserializer)
+        .map_err(|error| problem(error.inner().classify(), validation_error(&error)))?;
+    deserializer.end().map_err(|error| {
+        let error = ValidationError::new(error.to_string(), Source::Body { pointer: None });
+        problem(Category::Syntax, error)
+    })?;
+
+    Ok(value)
+}
+
+fn problem(category: Category, error: ValidationError) -> ProblemDetails<ValidationErrors> {
+    let (status, detail) = match category {
+        Category::Data => (
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "Failed to deserialize the JSON body into the target type",
+        ),
+        Category::Syntax | Category::Eof | Category::Io => (
+            StatusCode::BAD_REQUEST,
+            "Failed to parse the request body as JSON",
+        ),
+    };
+
+    ProblemDetails::new(status)
+        .detail(detail)
+        .extension(ValidationErrors {
+            errors: vec![error],
+        })
+}
+
+/// The validation error of a JSON value that failed to deserialize.
+///
+/// Syntax errors have no pointer, as the body has no structure to point in.
+pub(crate) fn validation_error(
+    error: &serde_path_to_error::Error<serde_json::Error>,
+) -> ValidationError {
+    let pointer = match error.inner().classify() {
+        Category::Data => Some(JsonPointer::from(error.path())),
+        Category::Syntax | Category::Eof | Category::Io => None,
+    };
+
+    ValidationError::new(error.inner().to_string(), Source::Body { pointer })
+}
+
+impl From<&serde_path_to_error::Path> for JsonPointer {
+    fn from(path: &serde_path_to_error::Path) -> Self {
+        use serde_path_to_error::Segment;
+
+        let mut pointer = Self::root();
+        for segment in path {
+            match segment {
+                Segment::Seq { index } => pointer.push_index(*index),
+                Segment::Map { key } | Segment::Enum { variant: key } => pointer.push_key(key),
+                Segment::Unknown => break,
+            }
+        }
+        pointer
+    }
+}
@@ -0,0 +1,114 @@
+//! Documentation pages for the registered problem types, so their `type`
+//! URIs resolve.
+use axum::{
+    Router,
+    response::{IntoResponse, Response},
+    routing::get,
+};
+use http::{
+    HeaderMap, HeaderValue, Uri,
+    header::{CONTENT_TYPE, VARY},
+};
+
+use crate::{ProblemFormat, ProblemType, ProblemTypeRegistry, TEXT_HTML, html::escape};
+
+impl ProblemTypeRegistry {
+    /// A router serving a documentation page at the path of every registered
+    /// problem type URI.
+    ///
+    /// The page is JSON when the request prefers `application/json`, as
+    /// negotiated by [`ProblemFormat`], and HTML otherwise.
+    ///
+    /// Problem types whose URI has no path, such as `about:blank`, are
+    /// skipped, as are those whose path is already served by a previously
+    /// registered type, and those whose path the router would read as a
+    /// capture or a wildcard, such as `/probs/{id}`.
+    pub fn docs_router<S>(&self) -> Router<S>
+    where
+        S: Clone + Send + Sync + 'static,
+    {
+        let mut paths = Vec::new();
+        let mut router = Router::new();
+
+        for problem_type in self {
+            let Some(path) = docs_path(problem_type.uri) else {
+                continue;
+            };
+            if paths.contains(&path) {
+                continue;
+            }
+
+            let problem_type = *problem_type;
+            router = router.route(
+                &path,
+                get(move |headers: HeaderMap| async move { docs_page(&problem_type, &headers) }),
+            );
+            paths.push(path);
+        }
+
+        router
+    }
+}
+
+/// The path a problem type URI dereferences to on this server.
+fn docs_path(uri: &str) -> Option<String> {
+    let uri = uri.parse::<Uri>().ok()?;
+    if uri
+        .scheme()
+        .is_some_and(|scheme| scheme != "http" && scheme != "https")
+    {
+        return None;
+    }
+
+    let path = uri.path();
+    let is_literal = path
+        .split('/')
+        .all(|segment| !segment.starts_with(':') && !segment.contains(['{', '}', '*']));
+    (path.starts_with('/') && path != "/" && is_literal).then(|| path.to_owned())
+}
+
+fn docs_page(problem_type: &ProblemType, headers: &HeaderMap) -> Response {
+    if ProblemFormat::from_headers(headers) == ProblemFormat::Json {
+        let body = serde_json::json!({
+            "type": problem_type.uri,
+            "title": problem_type.title,
+            "status": problem_type.status.as_u16(),
+            "description": problem_type.description,
+        });
+
+        return (
+            [
+                (CONTENT_TYPE, HeaderValue::from_static("application/json")),
+                (VARY, HeaderValue::from_static("accept")),
+            ],
+            body.to_string(),
+        )
+            .into_response();
+    }
+
+    let title = escape(problem_type.title);
+    let body = format!(
+        "<!DOCTYPE html>\n\
+         <html>\n\
+         <head><meta charset=\"utf-8\"><title>{title}</title></head>\n\
+         <body>\n\
+         <h1>{title}</h1>\n\
+         <p><code>{uri}</code></p>\n\
+         <p>Status: {status}</p>\n\
+         <p>{description}</p>\n\
+         </body>\n\
+         </html>\n",
+        uri = escape(problem_type.uri),
+        status = problem_type.status,
+        description = escape(problem_type.description),
+    );
+
+    (
+        [
+            (CONTENT_TYPE, TEXT_HTML),
+            (VARY, HeaderValue::from_static("accept")),
+        ],
+        body,
+    )
+        .into_response()
+}
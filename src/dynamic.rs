@@ -0,0 +1,33 @@
+//! Extension members assembled at runtime.
+use serde_json::{Map, Value};
+
+use crate::ProblemDetails;
+
+/// Extension members that are not known until runtime, e.g. when several
+/// layers of middleware each add their own.
+pub type DynamicExtensions = Map<String, Value>;
+
+impl ProblemDetails<DynamicExtensions> {
+    /// Inserts an extension member, returning the value it replaced.
+    pub fn insert_extension(
+        &mut self,
+        key: impl Into<String>,
+        value: impl Into<Value>,
+    ) -> Option<Value> {
+        self.extensions
+            .get_or_insert_with(Map::new)
+            .insert(key.into(), value.into())
+    }
+
+    pub fn get_extension(&self, key: &str) -> Option<&Value> {
+        self.extensions.as_ref()?.get(key)
+    }
+
+    pub fn get_extension_mut(&mut self, key: &str) -> Option<&mut Value> {
+        self.extensions.as_mut()?.get_mut(key)
+    }
+
+    pub fn remove_extension(&mut self, key: &str) -> Option<Value> {
+        self.extensions.as_mut()?.remove(key)
+    }
+}
@@ -0,0 +1,233 @@
+//! Problems for the rejections of the axum extractors, and extractors
+//! rejecting with them.
+use std::{borrow::Cow, error::Error};
+
+use axum::{
+    Form, Json,
+    extract::{
+        FromRequest, FromRequestParts, Path, Query, Request,
+        path::ErrorKind,
+        rejection::{FormRejection, JsonRejection, PathRejection, QueryRejection},
+    },
+};
+use http::{StatusCode, header::CONTENT_TYPE, request::Parts};
+
+use crate::{
+    ProblemDetails, Source, ValidationError, ValidationErrors, deserialize::validation_error,
+};
+
+/// A problem with the given validation errors, if any.
+fn problem(
+    status: StatusCode,
+    detail: String,
+    errors: Vec<ValidationError>,
+) -> ProblemDetails<ValidationErrors> {
+    let problem = ProblemDetails::new(status).detail(detail);
+    if errors.is_empty() {
+        problem
+    } else {
+        problem.extension(ValidationErrors { errors })
+    }
+}
+
+fn content_type_error(detail: String) -> ValidationError {
+    ValidationError::new(
+        detail,
+        Source::Header {
+            name: Cow::Borrowed(CONTENT_TYPE.as_str()),
+        },
+    )
+}
+
+/// The message of the error behind a rejection.
+fn source_message(rejection: &(dyn Error + 'static)) -> String {
+    rejection
+        .source()
+        .map_or_else(String::new, ToString::to_string)
+}
+
+/// The error with the path to the JSON value that failed to deserialize.
+fn json_path_error<'a>(
+    error: &'a (dyn Error + 'static),
+) -> Option<&'a serde_path_to_error::Error<serde_json::Error>> {
+    let mut source = error.source();
+    while let Some(error) = source {
+        if let Some(error) = error.downcast_ref() {
+            return Some(error);
+        }
+        source = error.source();
+    }
+    None
+}
+
+impl From<JsonRejection> for ProblemDetails<ValidationErrors> {
+    fn from(rejection: JsonRejection) -> Self {
+        let errors = match &rejection {
+            JsonRejection::JsonDataError(error) => json_path_error(error)
+                .map(validation_error)
+                .into_iter()
+                .collect(),
+            JsonRejection::JsonSyntaxError(error) => match json_path_error(error) {
+                Some(error) => vec![validation_error(error)],
+                None => vec![ValidationError::new(
+                    error.body_text(),
+                    Source::Body { pointer: None },
+                )],
+            },
+            JsonRejection::MissingJsonContentType(error) => {
+                vec![content_type_error(error.body_text())]
+            }
+            _ => Vec::new(),
+        };
+
+        problem(rejection.status(), rejection.body_text(), errors)
+    }
+}
+
+impl From<QueryRejection> for ProblemDetails<ValidationErrors> {
+    fn from(rejection: QueryRejection) -> Self {
+        let errors = match &rejection {
+            QueryRejection::FailedToDeserializeQueryString(error) => vec![ValidationError::new(
+                source_message(error),
+                Source::Query { parameter: None },
+            )],
+            _ => Vec::new(),
+        };
+
+        problem(rejection.status(), rejection.body_text(), errors)
+    }
+}
+
+impl From<PathRejection> for ProblemDetails<ValidationErrors> {
+    fn from(rejection: PathRejection) -> Self {
+        let errors = match &rejection {
+            PathRejection::FailedToDeserializePathParams(error) => {
+                let parameter = match error.kind() {
+                    ErrorKind::ParseErrorAtKey { key, .. }
+                    | ErrorKind::InvalidUtf8InPathParam { key }
+                    | ErrorKind::DeserializeError { key, .. } => Some(Cow::Owned(key.clone())),
+                    _ => None,
+                };
+                vec![ValidationError::new(
+                    error.kind().to_string(),
+                    Source::Path { parameter },
+                )]
+            }
+            _ => Vec::new(),
+        };
+
+        problem(rejection.status(), rejection.body_text(), errors)
+    }
+}
+
+impl From<FormRejection> for ProblemDetails<ValidationErrors> {
+    fn from(rejection: FormRejection) -> Self {
+        let errors = match &rejection {
+            FormRejection::InvalidFormContentType(error) => {
+                vec![content_type_error(error.body_text())]
+            }
+            FormRejection::FailedToDeserializeForm(error) => vec![ValidationError::new(
+                source_message(error),
+                Source::Query { parameter: None },
+            )],
+            FormRejection::FailedToDeserializeFormBody(error) => vec![ValidationError::new(
+                source_message(error),
+                Source::Form { field: None },
+            )],
+            _ => Vec::new(),
+        };
+
+        problem(rejection.status(), rejection.body_text(), errors)
+    }
+}
+
+/// Like [`Json`], but rejects with a [`ProblemDetails`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ProblemJson<T>(pub T);
+
+impl<T, S> FromRequest<S> for ProblemJson<T>
+where
+    Json<T>: FromRequest<S, Rejection = JsonRejection>,
+    S: Send + Sync,
+{
+    type Rejection = ProblemDetails<ValidationErrors>;
+
+    async fn from_request(request: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let Json(value) = Json::from_request(request, state).await?;
+        Ok(Self(value))
+    }
+}
+
+/// Like [`Form`], but rejects with a [`ProblemDetails`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ProblemForm<T>(pub T);
+
+impl<T, S> FromRequest<S> for ProblemForm<T>
+where
+    Form<T>: FromRequest<S, Rejection = FormRejection>,
+    S: Send + Sync,
+{
+    type Rejection = ProblemDetails<ValidationErrors>;
+
+    async fn from_request(request: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let Form(value) = Form::from_request(request, state).await?;
+        Ok(Self(value))
+    }
+}
+
+/// Like [`Query`], but rejects with a [`ProblemDetails`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ProblemQuery<T>(pub T);
+
+impl<T, S> FromRequestParts<S> for ProblemQuery<T>
+where
+    Query<T>: FromRequestParts<S, Rejection = QueryRejection>,
+    S: Send + Sync,
+{
+    type Rejection = ProblemDetails<ValidationErrors>;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let Query(value) = Query::from_request_parts(parts, state).await?;
+        Ok(Self(value))
+    }
+}
+
+/// Like [`Path`], but rejects with a [`ProblemDetails`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ProblemPath<T>(pub T);
+
+impl<T, S> FromRequestParts<S> for ProblemPath<T>
+where
+    Path<T>: FromRequestParts<S, Rejection = PathRejection>,
+    S: Send + Sync,
+{
+    type Rejection = ProblemDetails<ValidationErrors>;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let Path(value) = Path::from_request_parts(parts, state).await?;
+        Ok(Self(value))
+    }
+}
+
+/// Like [`ProblemJson`], but also validates the value with [`garde`],
+/// rejecting invalid ones with an `422 Unprocessable Entity` problem.
+#[cfg(feature = "garde")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ProblemValidJson<T>(pub T);
+
+#[cfg(feature = "garde")]
+impl<T, S> FromRequest<S> for ProblemValidJson<T>
+where
+    Json<T>: FromRequest<S, Rejection = JsonRejection>,
+    T: garde::Validate,
+    T::Context: Default,
+    S: Send + Sync,
+{
+    type Rejection = ProblemDetails<ValidationErrors>;
+
+    async fn from_request(request: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let ProblemJson(value) = ProblemJson::from_request(request, state).await?;
+        value.validate()?;
+        Ok(Self(value))
+    }
+}
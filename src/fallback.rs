@@ -0,0 +1,113 @@
+//! The problem sent when a [`ProblemDetails`] cannot be serialized.
+use std::{
+    borrow::Cow,
+    error::Error,
+    fmt,
+    sync::{LazyLock, OnceLock},
+};
+
+use bytes::Bytes;
+use http::{HeaderName, HeaderValue, StatusCode, header::CONTENT_TYPE};
+
+use crate::{APPLICATION_PROBLEM_JSON, ProblemDetails};
+
+/// The default fallback problem body.
+pub static INTERNAL_SERVER_ERROR_PROBLEM: LazyLock<Bytes> = LazyLock::new(|| {
+    let problem = ProblemDetails::<()> {
+        type_: Cow::Borrowed("internal_server_error"),
+        status: Some(StatusCode::INTERNAL_SERVER_ERROR.as_u16()),
+        title: Some(Cow::Borrowed("Internal Server Error")),
+        detail: Some(Cow::Borrowed(
+            "Something went wrong when processing your request. Please try again later.",
+        )),
+        ..Default::default()
+    };
+
+    serde_json::to_vec(&problem)
+        .expect("the internal server error problem is serializable")
+        .into()
+});
+
+/// The `500 Internal Server Error` response carrying the default fallback
+/// problem.
+#[deprecated(
+    since = "0.2.0",
+    note = "use `internal_server_error()`, which also sends the problem set with `set_fallback_problem`"
+)]
+pub const INTERNAL_SERVER_ERROR: (StatusCode, [(HeaderName, HeaderValue); 1], &[u8]) = (
+    StatusCode::INTERNAL_SERVER_ERROR,
+    [(CONTENT_TYPE, APPLICATION_PROBLEM_JSON)],
+    br#"{"type":"internal_server_error","status":500,"title":"Internal Server Error","detail":"Something went wrong when processing your request. Please try again later."}"#,
+);
+
+static FALLBACK_PROBLEM: OnceLock<Bytes> = OnceLock::new();
+
+/// Replaces [`INTERNAL_SERVER_ERROR_PROBLEM`] as the fallback problem body.
+///
+/// The fallback is always sent as `500 Internal Server Error`, so a problem
+/// carrying any other status is rejected. The fallback can only be set once,
+/// ideally at startup before any problem is sent.
+pub fn set_fallback_problem<Extension>(
+    problem: &ProblemDetails<Extension>,
+) -> Result<(), SetFallbackError>
+where
+    Extension: serde::Serialize,
+{
+    if problem.status.is_some() && problem.status_code() != StatusCode::INTERNAL_SERVER_ERROR {
+        return Err(SetFallbackError::Status(problem.status_code()));
+    }
+    let body = serde_json::to_vec(problem).map_err(SetFallbackError::Json)?;
+
+    FALLBACK_PROBLEM
+        .set(body.into())
+        .map_err(|_| SetFallbackError::AlreadySet)
+}
+
+/// The problem body sent when a [`ProblemDetails`] fails to serialize.
+pub fn fallback_problem() -> Bytes {
+    FALLBACK_PROBLEM
+        .get()
+        .unwrap_or(&INTERNAL_SERVER_ERROR_PROBLEM)
+        .clone()
+}
+
+/// The `500 Internal Server Error` response carrying the [`fallback_problem`].
+pub fn internal_server_error() -> (StatusCode, [(HeaderName, HeaderValue); 1], Bytes) {
+    (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        [(CONTENT_TYPE, APPLICATION_PROBLEM_JSON)],
+        fallback_problem(),
+    )
+}
+
+/// The reasons the fallback problem could not be set.
+#[derive(Debug)]
+pub enum SetFallbackError {
+    /// A fallback problem was already set.
+    AlreadySet,
+    /// The problem carries a status other than `500 Internal Server Error`.
+    Status(StatusCode),
+    /// The problem failed to serialize.
+    Json(serde_json::Error),
+}
+
+impl fmt::Display for SetFallbackError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AlreadySet => f.write_str("the fallback problem was already set"),
+            Self::Status(status) => {
+                write!(f, "the fallback problem has status {status} instead of 500")
+            }
+            Self::Json(_) => f.write_str("the fallback problem failed to serialize"),
+        }
+    }
+}
+
+impl Error for SetFallbackError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::AlreadySet | Self::Status(_) => None,
+            Self::Json(error) => Some(error),
+        }
+    }
+}
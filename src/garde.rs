@@ -0,0 +1,52 @@
+//! Conversion of the reports of the [`garde`] crate.
+use http::StatusCode;
+
+use crate::{JsonPointer, ProblemDetails, Source, ValidationError, ValidationErrors};
+
+/// One validation error per failed rule, pointing at the invalid field.
+impl From<garde::Report> for ValidationErrors {
+    fn from(report: garde::Report) -> Self {
+        let errors = report
+            .into_inner()
+            .into_iter()
+            .map(|(path, error)| {
+                ValidationError::new(
+                    error.message(),
+                    Source::Body {
+                        pointer: Some(JsonPointer::from(&path)),
+                    },
+                )
+            })
+            .collect();
+
+        Self { errors }
+    }
+}
+
+/// An `422 Unprocessable Entity` problem with the validation errors.
+impl From<garde::Report> for ProblemDetails<ValidationErrors> {
+    fn from(report: garde::Report) -> Self {
+        ProblemDetails::new(StatusCode::UNPROCESSABLE_ENTITY)
+            .detail("The request failed validation")
+            .extension(report.into())
+    }
+}
+
+impl From<&garde::Path> for JsonPointer {
+    fn from(path: &garde::Path) -> Self {
+        // A path serializes to its `[kind, component]` pairs from the root
+        // down, the only public way to tell keys from single field wrappers,
+        // such as `Option`, which have the kind `none` and add no component.
+        let components: Vec<(String, String)> = serde_json::to_value(path)
+            .and_then(serde_json::from_value)
+            .unwrap_or_default();
+
+        let mut pointer = Self::root();
+        for (kind, component) in components {
+            if kind != "none" {
+                pointer.push_key(component);
+            }
+        }
+        pointer
+    }
+}
@@ -0,0 +1,238 @@
+//! HTML error pages for browsers.
+use std::{borrow::Cow, fmt::Write, sync::OnceLock};
+
+#[cfg(feature = "axum")]
+use axum_core::response::{IntoResponse, Response};
+use http::HeaderValue;
+#[cfg(feature = "axum")]
+use http::header::CONTENT_TYPE;
+
+#[cfg(feature = "axum")]
+use crate::internal_server_error;
+use crate::{ABOUT_BLANK, ProblemDetails, Source, ValidationError};
+
+pub const TEXT_HTML: HeaderValue = HeaderValue::from_static("text/html; charset=utf-8");
+
+/// Renders an [`HtmlPage`] into a complete HTML document.
+pub type HtmlTemplate = fn(&HtmlPage<'_>) -> String;
+
+static HTML_TEMPLATE: OnceLock<HtmlTemplate> = OnceLock::new();
+
+/// Replaces [`HtmlPage::to_default_html`] as the template of the HTML pages.
+///
+/// The template can only be set once, ideally at startup. The rejected
+/// template is returned if one was already set.
+pub fn set_html_template(template: HtmlTemplate) -> Result<(), HtmlTemplate> {
+    HTML_TEMPLATE.set(template)
+}
+
+/// The members of a problem to render in an HTML page.
+///
+/// Every string is already HTML escaped, so it can be inserted as is in text
+/// and quoted attribute values.
+#[derive(Debug)]
+pub struct HtmlPage<'a> {
+    pub title: Cow<'a, str>,
+    pub status: u16,
+    pub detail: Option<Cow<'a, str>>,
+    pub instance: Option<Cow<'a, str>>,
+    /// The problem type URI, `None` for [`ABOUT_BLANK`].
+    pub type_: Option<Cow<'a, str>>,
+    /// Whether `type_` is an `http(s)` or relative URI that is safe to link to.
+    pub type_is_link: bool,
+    pub errors: Vec<HtmlValidationError>,
+}
+
+/// A [`ValidationError`] to render in an HTML page, already HTML escaped.
+#[derive(Debug)]
+pub struct HtmlValidationError {
+    pub detail: String,
+    /// The request part, e.g. `body` or `header`.
+    pub source: String,
+    /// Where in the request part, e.g. the JSON pointer or header name.
+    pub location: Option<String>,
+}
+
+impl HtmlPage<'_> {
+    /// Renders the page with the template shipped with the crate.
+    pub fn to_default_html(&self) -> String {
+        let mut html = String::with_capacity(1024);
+        html.push_str("<!DOCTYPE html>\n<html>\n");
+        writeln!(
+            html,
+            "<head><meta charset=\"utf-8\"><title>{}</title></head>",
+            self.title,
+        )
+        .unwrap();
+        html.push_str("<body>\n");
+        writeln!(html, "<h1>{}</h1>", self.title).unwrap();
+        writeln!(html, "<p>Status: {}</p>", self.status).unwrap();
+        if let Some(detail) = &self.detail {
+            writeln!(html, "<p>{detail}</p>").unwrap();
+        }
+        if let Some(instance) = &self.instance {
+            writeln!(html, "<p>Occurrence: <code>{instance}</code></p>").unwrap();
+        }
+        match &self.type_ {
+            Some(type_) if self.type_is_link => {
+                writeln!(html, "<p>Problem type: <a href=\"{type_}\">{type_}</a></p>").unwrap();
+            }
+            Some(type_) => writeln!(html, "<p>Problem type: <code>{type_}</code></p>").unwrap(),
+            None => {}
+        }
+        if !self.errors.is_empty() {
+            html.push_str("<table>\n");
+            html.push_str("<tr><th>Source</th><th>Location</th><th>Detail</th></tr>\n");
+            for error in &self.errors {
+                writeln!(
+                    html,
+                    "<tr><td>{}</td><td><code>{}</code></td><td>{}</td></tr>",
+                    error.source,
+                    error.location.as_deref().unwrap_or_default(),
+                    error.detail,
+                )
+                .unwrap();
+            }
+            html.push_str("</table>\n");
+        }
+        html.push_str("</body>\n</html>\n");
+        html
+    }
+}
+
+impl<Extension> ProblemDetails<Extension>
+where
+    Extension: serde::Serialize,
+{
+    /// The members of the problem to render in an HTML page.
+    ///
+    /// The validation errors are taken from an `errors` extension member
+    /// shaped like [`ValidationErrors`](crate::ValidationErrors).
+    pub fn html_page(&self) -> HtmlPage<'_> {
+        let status = self.status_code();
+        let title = self
+            .title
+            .as_deref()
+            .or(status.canonical_reason())
+            .unwrap_or("Error");
+        let type_ = (self.type_ != ABOUT_BLANK).then_some(&*self.type_);
+
+        HtmlPage {
+            title: escape(title),
+            status: status.as_u16(),
+            detail: self.detail.as_deref().map(escape),
+            instance: self.instance.as_deref().map(escape),
+            type_: type_.map(escape),
+            type_is_link: type_.is_some_and(is_safe_link),
+            errors: self.validation_errors().iter().map(html_error).collect(),
+        }
+    }
+
+    /// Renders the problem as an HTML page, with the template set by
+    /// [`set_html_template`] or the default one.
+    pub fn to_html(&self) -> String {
+        let page = self.html_page();
+        match HTML_TEMPLATE.get() {
+            Some(template) => template(&page),
+            None => page.to_default_html(),
+        }
+    }
+
+    fn validation_errors(&self) -> Vec<ValidationError> {
+        let Some(extensions) = &self.extensions else {
+            return Vec::new();
+        };
+
+        serde_json::to_value(extensions)
+            .ok()
+            .and_then(|mut extensions| extensions.get_mut("errors").map(serde_json::Value::take))
+            .and_then(|errors| serde_json::from_value(errors).ok())
+            .unwrap_or_default()
+    }
+}
+
+fn html_error(error: &ValidationError) -> HtmlValidationError {
+    let (source, location) = match &error.source {
+        Source::Body { pointer } => (
+            "body",
+            pointer.as_ref().map(|pointer| pointer.to_string().into()),
+        ),
+        Source::Header { name } => ("header", Some(Cow::Borrowed(&**name))),
+        Source::Query { parameter } => ("query", parameter.as_deref().map(Cow::Borrowed)),
+        Source::Path { parameter } => ("path", parameter.as_deref().map(Cow::Borrowed)),
+        Source::Cookie { name } => ("cookie", Some(Cow::Borrowed(&**name))),
+        Source::Form { field } => ("form", field.as_deref().map(Cow::Borrowed)),
+        Source::Multipart { part, pointer } => (
+            "multipart",
+            Some(match pointer {
+                Some(pointer) => format!("{part}{pointer}").into(),
+                None => Cow::Borrowed(&**part),
+            }),
+        ),
+    };
+
+    HtmlValidationError {
+        detail: escape(&error.detail).into_owned(),
+        source: source.to_owned(),
+        location: location.map(|location| escape(&location).into_owned()),
+    }
+}
+
+/// Whether the URI can be used as a link without running a script.
+fn is_safe_link(uri: &str) -> bool {
+    let scheme = uri.split_once(':').map(|(scheme, _)| scheme);
+    match scheme {
+        Some(scheme) if !scheme.contains('/') => {
+            scheme.eq_ignore_ascii_case("http") || scheme.eq_ignore_ascii_case("https")
+        }
+        _ => true,
+    }
+}
+
+/// Sends a [`ProblemDetails`] as an HTML page.
+#[cfg(feature = "axum")]
+#[derive(Debug)]
+pub struct ProblemHtml<Extension>(pub ProblemDetails<Extension>);
+
+#[cfg(feature = "axum")]
+impl<Extension> IntoResponse for ProblemHtml<Extension>
+where
+    Extension: serde::Serialize,
+{
+    fn into_response(self) -> Response {
+        let Self(mut problem) = self;
+        // Like the other formats, a problem whose extension members do not
+        // serialize is replaced by the fallback problem.
+        if serde_json::to_writer(std::io::sink(), &problem).is_err() {
+            return internal_server_error().into_response();
+        }
+
+        (
+            problem.status_code(),
+            std::mem::take(&mut problem.headers),
+            [(CONTENT_TYPE, TEXT_HTML)],
+            problem.to_html(),
+        )
+            .into_response()
+    }
+}
+
+/// Escapes the characters that are special in HTML text and attribute values.
+pub(crate) fn escape(text: &str) -> Cow<'_, str> {
+    if !text.contains(['&', '<', '>', '"', '\'']) {
+        return Cow::Borrowed(text);
+    }
+
+    let mut escaped = String::with_capacity(text.len() + 16);
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    Cow::Owned(escaped)
+}
@@ -0,0 +1,117 @@
+//! A [`tower_layer::Layer`] turning the error responses produced outside
+//! the handlers into problems.
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll, ready},
+};
+
+use bytes::Bytes;
+use http::{Request, Response, header::CONTENT_TYPE};
+use http_body_util::{Either, Full};
+use pin_project_lite::pin_project;
+use tower_layer::Layer;
+use tower_service::Service;
+
+use crate::{APPLICATION_PROBLEM_JSON, ProblemDetails, fallback_problem, response::BODY_HEADERS};
+
+/// Rewrites 4xx and 5xx responses with an empty or `text/plain` body, such as
+/// the `404` of a router or the `413` of a body limit, into a
+/// [`ProblemDetails`] titled with the canonical reason of their status.
+///
+/// The original body is dropped, but headers like `Allow`, `Retry-After` or
+/// `WWW-Authenticate` are kept.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ProblemDetailsLayer;
+
+impl ProblemDetailsLayer {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<S> Layer<S> for ProblemDetailsLayer {
+    type Service = ProblemDetailsService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        ProblemDetailsService { inner }
+    }
+}
+
+/// The service applied by [`ProblemDetailsLayer`].
+#[derive(Clone, Debug)]
+pub struct ProblemDetailsService<S> {
+    inner: S,
+}
+
+impl<S, ReqBody, ResBody> Service<Request<ReqBody>> for ProblemDetailsService<S>
+where
+    S: Service<Request<ReqBody>, Response = Response<ResBody>>,
+{
+    type Response = Response<Either<ResBody, Full<Bytes>>>;
+    type Error = S::Error;
+    type Future = ResponseFuture<S::Future>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request<ReqBody>) -> Self::Future {
+        ResponseFuture {
+            inner: self.inner.call(request),
+        }
+    }
+}
+
+pin_project! {
+    /// The response future of [`ProblemDetailsService`].
+    pub struct ResponseFuture<F> {
+        #[pin]
+        inner: F,
+    }
+}
+
+impl<F, ResBody, E> Future for ResponseFuture<F>
+where
+    F: Future<Output = Result<Response<ResBody>, E>>,
+{
+    type Output = Result<Response<Either<ResBody, Full<Bytes>>>, E>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let response = ready!(self.project().inner.poll(cx))?;
+        Poll::Ready(Ok(into_problem(response)))
+    }
+}
+
+fn into_problem<B>(response: Response<B>) -> Response<Either<B, Full<Bytes>>> {
+    let status = response.status();
+    if !(status.is_client_error() || status.is_server_error()) || !is_bare(&response) {
+        return response.map(Either::Left);
+    }
+
+    let (mut parts, _) = response.into_parts();
+    for header in BODY_HEADERS {
+        parts.headers.remove(header);
+    }
+    parts.headers.insert(CONTENT_TYPE, APPLICATION_PROBLEM_JSON);
+
+    let body = serde_json::to_vec(&ProblemDetails::<()>::new(status))
+        .map(Bytes::from)
+        .unwrap_or_else(|_| fallback_problem());
+    Response::from_parts(parts, Either::Right(Full::new(body)))
+}
+
+/// Whether the response body is empty or plain text, like the errors of
+/// routers and middleware. Other bodies, such as problems or the HTML and
+/// JSON of [`ProblemFormat`](crate::ProblemFormat), are sent as is.
+fn is_bare<B>(response: &Response<B>) -> bool {
+    let Some(content_type) = response.headers().get(CONTENT_TYPE) else {
+        return true;
+    };
+
+    content_type
+        .to_str()
+        .ok()
+        .and_then(|content_type| content_type.split(';').next())
+        .is_some_and(|essence| essence.trim().eq_ignore_ascii_case("text/plain"))
+}
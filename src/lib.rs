@@ -1,81 +1,290 @@
 //! Types to represent a problem detail error response.
 //!
 //! See [RFC 9457](https://www.rfc-editor.org/rfc/rfc9457.html) for more details.
-use std::borrow::Cow;
+use std::{borrow::Cow, fmt};
 
-use bytes::{BufMut, BytesMut};
-use http::{header::CONTENT_TYPE, HeaderName, HeaderValue, StatusCode};
+use http::{HeaderMap, HeaderValue, StatusCode};
 
-#[derive(serde::Serialize, Debug)]
+#[cfg(feature = "actix")]
+mod actix;
+mod builder;
+mod client;
+mod deserialize;
+#[cfg(feature = "docs")]
+mod docs;
+mod dynamic;
+#[cfg(feature = "axum")]
+mod extract;
+mod fallback;
+#[cfg(feature = "garde")]
+mod garde;
+mod html;
+#[cfg(feature = "tower")]
+pub mod layer;
+mod negotiate;
+#[cfg(feature = "poem")]
+mod poem;
+mod pointer;
+mod problem_type;
+mod response;
+mod ser;
+#[cfg(feature = "validator")]
+mod validator;
+#[cfg(feature = "warp")]
+mod warp;
+#[cfg(feature = "xml")]
+mod xml;
+
+pub use client::FromResponseError;
+pub use deserialize::from_json_slice;
+pub use dynamic::DynamicExtensions;
+#[cfg(all(feature = "axum", feature = "garde"))]
+pub use extract::ProblemValidJson;
+#[cfg(feature = "axum")]
+pub use extract::{ProblemForm, ProblemJson, ProblemPath, ProblemQuery};
+#[allow(deprecated)]
+pub use fallback::INTERNAL_SERVER_ERROR;
+pub use fallback::{
+    INTERNAL_SERVER_ERROR_PROBLEM, SetFallbackError, fallback_problem, internal_server_error,
+    set_fallback_problem,
+};
+#[cfg(feature = "axum")]
+pub use html::ProblemHtml;
+pub use html::{HtmlPage, HtmlTemplate, HtmlValidationError, TEXT_HTML, set_html_template};
+#[cfg(feature = "tower")]
+pub use layer::{ProblemDetailsLayer, ProblemDetailsService};
+pub use negotiate::ProblemFormat;
+pub use pointer::{JsonPointer, ParseJsonPointerError};
+pub use problem_type::{DuplicateProblemType, ProblemType, ProblemTypeRegistry};
+pub use ser::{RESERVED_MEMBERS, ReservedMemberPolicy};
+// `self::` as `warp` also names the crate.
+#[cfg(feature = "warp")]
+pub use self::warp::recover_problem;
+#[cfg(all(feature = "axum", feature = "xml"))]
+pub use xml::ProblemXml;
+#[cfg(feature = "xml")]
+pub use xml::{APPLICATION_PROBLEM_XML, PROBLEM_XML_NAMESPACE, XmlError};
+
+/// Turns an error enum into a [`ProblemDetails`], see
+/// [`krabby_details_derive::Problem`].
+#[cfg(feature = "derive")]
+pub use krabby_details_derive::Problem;
+
+/// Items used by the code generated by the derive macro.
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "axum")]
+    pub use axum_core;
+    pub use http;
+
+    /// Serializes an extension member, as `null` when it fails to.
+    pub fn extension_value<T: serde::Serialize + ?Sized>(value: &T) -> serde_json::Value {
+        serde_json::to_value(value).unwrap_or_default()
+    }
+}
+
+#[derive(serde::Deserialize, Debug)]
 pub struct ProblemDetails<Extension> {
-    #[serde(rename = "type")]
+    /// A URI reference identifying the problem type, [`ABOUT_BLANK`] by
+    /// default.
+    #[serde(rename = "type", default = "about_blank")]
     pub type_: Cow<'static, str>,
-    pub status: u16,
-    pub title: Cow<'static, str>,
-    pub detail: Cow<'static, str>,
+    /// The HTTP status code. It is serialized as the
+    /// [`status_code`](Self::status_code) the problem is sent with, so the
+    /// two never disagree.
+    pub status: Option<u16>,
+    pub title: Option<Cow<'static, str>>,
+    pub detail: Option<Cow<'static, str>>,
+    /// A URI reference identifying this specific occurrence of the problem.
+    pub instance: Option<Cow<'static, str>>,
+    /// Additional members, serialized alongside the standard ones.
     #[serde(flatten)]
-    #[serde(skip_serializing_if = "Option::is_none")]
     pub extensions: Option<Extension>,
+    /// Overrides the HTTP status of the response, and the serialized
+    /// [`status`](Self::status) with it.
+    ///
+    /// When `None`, the response status is derived from
+    /// [`status`](Self::status), see [`ProblemDetails::status_code`].
+    #[serde(skip)]
+    pub response_status: Option<StatusCode>,
+    /// Additional headers sent with the response, such as `Retry-After`.
+    #[serde(skip)]
+    pub headers: HeaderMap,
+    /// What to do with extension members named like a standard member.
+    #[serde(skip)]
+    pub reserved_members: ReservedMemberPolicy,
+}
+
+impl<Extension> ProblemDetails<Extension> {
+    /// The HTTP status the problem is sent with.
+    ///
+    /// This is [`response_status`](Self::response_status) when set. Otherwise
+    /// it is [`status`](Self::status) if that is a client (4xx) or server
+    /// (5xx) error code, and `500 Internal Server Error` if it is missing or
+    /// is not.
+    pub fn status_code(&self) -> StatusCode {
+        if let Some(status) = self.response_status {
+            return status;
+        }
+
+        match self.status.map(StatusCode::from_u16) {
+            Some(Ok(status)) if status.is_client_error() || status.is_server_error() => status,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+/// The title and detail, e.g. `Not Found: No user with id 3`, or the type
+/// when there is no title.
+impl<Extension> fmt::Display for ProblemDetails<Extension> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let title = self.title.as_deref().unwrap_or(&self.type_);
+        match &self.detail {
+            Some(detail) => write!(f, "{title}: {detail}"),
+            None => f.write_str(title),
+        }
+    }
+}
+
+impl<Extension: fmt::Debug> std::error::Error for ProblemDetails<Extension> {}
+
+impl<Extension> Default for ProblemDetails<Extension> {
+    fn default() -> Self {
+        Self {
+            type_: about_blank(),
+            status: None,
+            title: None,
+            detail: None,
+            instance: None,
+            extensions: None,
+            response_status: None,
+            headers: HeaderMap::new(),
+            reserved_members: ReservedMemberPolicy::default(),
+        }
+    }
 }
 
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize, Debug)]
 pub struct ValidationErrors {
     pub errors: Vec<ValidationError>,
 }
 
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize, Debug)]
 pub struct ValidationError {
     pub detail: String,
+    /// A machine readable identifier of the failed constraint, e.g.
+    /// `too_short`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<Cow<'static, str>>,
+    /// The parameters of the failed constraint, e.g. `{ "min": 3 }`.
+    #[serde(default, skip_serializing_if = "serde_json::Map::is_empty")]
+    pub params: serde_json::Map<String, serde_json::Value>,
+    /// The value that failed the constraint. Leave it out, or
+    /// [redact](ValidationErrors::redact_rejected_values) it, when it may be
+    /// sensitive, e.g. a password.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rejected_value: Option<serde_json::Value>,
     #[serde(flatten)]
     pub source: Source,
 }
 
+impl ValidationError {
+    pub fn new(detail: impl Into<String>, source: Source) -> Self {
+        Self {
+            detail: detail.into(),
+            code: None,
+            params: serde_json::Map::new(),
+            rejected_value: None,
+            source,
+        }
+    }
+
+    pub fn code(mut self, code: impl Into<Cow<'static, str>>) -> Self {
+        self.code = Some(code.into());
+        self
+    }
+
+    /// Adds a parameter of the failed constraint.
+    pub fn param(mut self, name: impl Into<String>, value: impl Into<serde_json::Value>) -> Self {
+        self.params.insert(name.into(), value.into());
+        self
+    }
+
+    pub fn rejected_value(mut self, value: impl Into<serde_json::Value>) -> Self {
+        self.rejected_value = Some(value.into());
+        self
+    }
+}
+
+impl ValidationErrors {
+    /// Leaves the rejected values out, e.g. before sending the errors of a
+    /// login form.
+    pub fn redact_rejected_values(&mut self) {
+        for error in &mut self.errors {
+            error.rejected_value = None;
+        }
+    }
+}
+
 /// The request part where the problem occurred.
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize, Debug)]
 #[serde(tag = "source", rename_all = "snake_case")]
+#[non_exhaustive]
 pub enum Source {
     Body {
         /// A [JSON pointer](https://www.rfc-editor.org/info/rfc6901) targeted
         /// at the problematic body property.
-        pointer: Option<String>,
+        pointer: Option<JsonPointer>,
     },
     Header {
         /// The name of the problematic header.
         name: Cow<'static, str>,
     },
+    Query {
+        /// The name of the problematic query string parameter, when known.
+        parameter: Option<Cow<'static, str>>,
+    },
+    Path {
+        /// The name of the problematic path parameter, when known.
+        parameter: Option<Cow<'static, str>>,
+    },
+    Cookie {
+        /// The name of the problematic cookie.
+        name: Cow<'static, str>,
+    },
+    Form {
+        /// The name of the problematic `application/x-www-form-urlencoded`
+        /// body field, when known.
+        field: Option<Cow<'static, str>>,
+    },
+    Multipart {
+        /// The name of the problematic `multipart/form-data` part.
+        part: Cow<'static, str>,
+        /// A [JSON pointer](https://www.rfc-editor.org/info/rfc6901) targeted
+        /// at the problematic property of a JSON part.
+        pointer: Option<JsonPointer>,
+    },
 }
 
+#[cfg(feature = "axum")]
 impl<Extension> axum_core::response::IntoResponse for ProblemDetails<Extension>
 where
     Extension: serde::Serialize,
 {
     fn into_response(self) -> axum_core::response::Response {
-        // Use a small initial capacity of 128 bytes like serde_json::to_vec
-        // https://docs.rs/serde_json/1.0.82/src/serde_json/ser.rs.html#2189
-        let mut buf = BytesMut::with_capacity(128).writer();
-        match serde_json::to_writer(&mut buf, &self) {
-            Ok(()) => (
-                [(CONTENT_TYPE, APPLICATION_PROBLEM_JSON)],
-                buf.into_inner().freeze(),
-            )
-                .into_response(),
-            Err(_) => INTERNAL_SERVER_ERROR.into_response(),
-        }
+        self.into_http_response()
+            .map(axum_core::body::Body::from)
+            .into_response()
     }
 }
 
+fn about_blank() -> Cow<'static, str> {
+    Cow::Borrowed(ABOUT_BLANK)
+}
+
+/// The problem type to use when the problem has no additional semantics
+/// beyond that of the HTTP status code.
+pub const ABOUT_BLANK: &str = "about:blank";
+
 pub const APPLICATION_PROBLEM_JSON: HeaderValue =
     HeaderValue::from_static("application/problem+json");
-
-pub const INTERNAL_SERVER_ERROR: (StatusCode, [(HeaderName, HeaderValue); 1], &[u8]) = (
-    StatusCode::INTERNAL_SERVER_ERROR,
-    [(CONTENT_TYPE, APPLICATION_PROBLEM_JSON)],
-    INTERNAL_SERVER_ERROR_PROBLEM,
-);
-
-pub const INTERNAL_SERVER_ERROR_PROBLEM: &[u8] = br#"{
-    "type": "internal_server_error",
-    "title": "Internal Server Error",
-    "detail": "Something went wrong when processing your request. Please try again later."
-    "status": 500
-}"#;
@@ -0,0 +1,146 @@
+//! Content negotiation between the formats a problem can be sent in.
+#[cfg(feature = "axum")]
+use std::convert::Infallible;
+
+#[cfg(feature = "axum")]
+use axum_core::{
+    extract::FromRequestParts,
+    response::{IntoResponse, Response},
+};
+use http::{HeaderMap, header::ACCEPT};
+#[cfg(feature = "axum")]
+use http::{
+    HeaderValue,
+    header::{CONTENT_TYPE, VARY},
+    request::Parts,
+};
+
+#[cfg(feature = "axum")]
+use crate::{APPLICATION_PROBLEM_JSON, ProblemDetails, ProblemHtml};
+
+/// The format to send a problem in, negotiated from the `Accept` header of
+/// the request.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ProblemFormat {
+    /// `application/problem+json`, also used when nothing else matches.
+    #[default]
+    ProblemJson,
+    /// `application/problem+xml`, only negotiated with the `xml` feature.
+    /// Without it, problems are sent as `application/problem+json` instead.
+    ProblemXml,
+    /// `application/json`, for legacy clients that do not know about
+    /// `application/problem+json`.
+    Json,
+    /// `text/html`, for browsers.
+    Html,
+}
+
+impl ProblemFormat {
+    /// The preferred format of the given `Accept` headers.
+    ///
+    /// The media range with the highest quality wins, the first one on ties.
+    /// A format excluded with `q=0` is not chosen through a wildcard such as
+    /// `*/*` either.
+    pub fn from_headers(headers: &HeaderMap) -> Self {
+        let ranges: Vec<_> = headers
+            .get_all(ACCEPT)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(','))
+            .filter_map(|range| {
+                let mut params = range.split(';');
+                let media_type = params.next().unwrap_or_default().trim();
+                let quality = params
+                    .filter_map(|param| param.trim().strip_prefix("q="))
+                    .find_map(|quality| quality.trim().parse::<f32>().ok())
+                    .unwrap_or(1.0);
+                let format = Self::from_media_type(media_type)?;
+
+                Some((format, quality, media_type.ends_with("/*")))
+            })
+            .collect();
+
+        let excluded: Vec<_> = ranges
+            .iter()
+            .filter(|(_, quality, wildcard)| *quality <= 0.0 && !wildcard)
+            .map(|(format, ..)| *format)
+            .collect();
+
+        let mut best = None;
+        for (format, quality, wildcard) in ranges {
+            if wildcard && excluded.contains(&format) {
+                continue;
+            }
+            if quality > 0.0 && best.is_none_or(|(_, best)| quality > best) {
+                best = Some((format, quality));
+            }
+        }
+
+        best.map(|(format, _)| format).unwrap_or_default()
+    }
+
+    fn from_media_type(media_type: &str) -> Option<Self> {
+        let media_type = media_type.to_ascii_lowercase();
+        match media_type.as_str() {
+            "application/problem+json" | "application/*" | "*/*" => Some(Self::ProblemJson),
+            #[cfg(feature = "xml")]
+            "application/problem+xml" => Some(Self::ProblemXml),
+            "application/json" => Some(Self::Json),
+            "text/html" | "text/*" => Some(Self::Html),
+            _ => None,
+        }
+    }
+
+    /// Sends the problem in this format, with a `Vary: Accept` header.
+    ///
+    /// With `ProblemFormat` as an extractor, a handler answers each client in
+    /// the format it asked for:
+    ///
+    /// ```
+    /// use http::StatusCode;
+    /// use krabby_details::{ProblemDetails, ProblemFormat};
+    ///
+    /// async fn handler(format: ProblemFormat) -> axum_core::response::Response {
+    ///     format.respond(ProblemDetails::<()>::new(StatusCode::NOT_FOUND))
+    /// }
+    /// ```
+    #[cfg(feature = "axum")]
+    pub fn respond<Extension>(self, problem: ProblemDetails<Extension>) -> Response
+    where
+        Extension: serde::Serialize,
+    {
+        let mut response = match self {
+            Self::ProblemJson => problem.into_response(),
+            #[cfg(feature = "xml")]
+            Self::ProblemXml => crate::ProblemXml(problem).into_response(),
+            #[cfg(not(feature = "xml"))]
+            Self::ProblemXml => problem.into_response(),
+            Self::Json => {
+                let mut response = problem.into_response();
+                let headers = response.headers_mut();
+                if headers.get(CONTENT_TYPE) == Some(&APPLICATION_PROBLEM_JSON) {
+                    headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+                }
+                response
+            }
+            Self::Html => ProblemHtml(problem).into_response(),
+        };
+
+        response
+            .headers_mut()
+            .append(VARY, HeaderValue::from_static("accept"));
+        response
+    }
+}
+
+#[cfg(feature = "axum")]
+impl<S> FromRequestParts<S> for ProblemFormat
+where
+    S: Send + Sync,
+{
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _: &S) -> Result<Self, Self::Rejection> {
+        Ok(Self::from_headers(&parts.headers))
+    }
+}
@@ -0,0 +1,43 @@
+//! Responses and errors for poem.
+use poem::{Body, IntoResponse, Response, ResponseParts, error::ResponseError};
+use serde::Serialize;
+
+use crate::ProblemDetails;
+
+/// Sends the problem like its axum `IntoResponse` does.
+impl<Extension> IntoResponse for ProblemDetails<Extension>
+where
+    Extension: Serialize + Send,
+{
+    fn into_response(self) -> Response {
+        poem_response(self.into_http_response())
+    }
+}
+
+/// Lets handlers return the problem as their error, e.g. with `?`.
+impl<Extension> ResponseError for ProblemDetails<Extension>
+where
+    Extension: Serialize,
+{
+    fn status(&self) -> http::StatusCode {
+        self.status_code()
+    }
+
+    fn as_response(&self) -> Response {
+        poem_response(self.to_http_response())
+    }
+}
+
+fn poem_response(response: http::Response<bytes::Bytes>) -> Response {
+    let (parts, body) = response.into_parts();
+
+    Response::from_parts(
+        ResponseParts {
+            status: parts.status,
+            version: parts.version,
+            headers: parts.headers,
+            extensions: parts.extensions,
+        },
+        Body::from(body),
+    )
+}
@@ -0,0 +1,169 @@
+//! [JSON pointers](https://www.rfc-editor.org/info/rfc6901) to the
+//! problematic values of a JSON body.
+use std::{borrow::Cow, error::Error, fmt, str::FromStr};
+
+use serde_json::Value;
+
+/// A JSON pointer, made of unescaped reference tokens.
+///
+/// ```
+/// use krabby_details::JsonPointer;
+///
+/// let pointer = JsonPointer::root().with_key("pets").with_index(0).with_key("a/b");
+///
+/// assert_eq!(pointer.to_string(), "/pets/0/a~1b");
+/// assert_eq!("/pets/0/a~1b".parse(), Ok(pointer));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct JsonPointer {
+    tokens: Vec<String>,
+}
+
+impl JsonPointer {
+    /// The pointer to the whole document.
+    pub fn root() -> Self {
+        Self::default()
+    }
+
+    pub fn push_key(&mut self, key: impl Into<String>) {
+        self.tokens.push(key.into());
+    }
+
+    pub fn push_index(&mut self, index: usize) {
+        self.tokens.push(index.to_string());
+    }
+
+    pub fn with_key(mut self, key: impl Into<String>) -> Self {
+        self.push_key(key);
+        self
+    }
+
+    pub fn with_index(mut self, index: usize) -> Self {
+        self.push_index(index);
+        self
+    }
+
+    /// Removes the last reference token, returning it.
+    pub fn pop(&mut self) -> Option<String> {
+        self.tokens.pop()
+    }
+
+    pub fn is_root(&self) -> bool {
+        self.tokens.is_empty()
+    }
+
+    /// The unescaped reference tokens.
+    pub fn tokens(&self) -> impl Iterator<Item = &str> {
+        self.tokens.iter().map(String::as_str)
+    }
+
+    /// The value the pointer refers to in `value`.
+    pub fn resolve<'a>(&self, value: &'a Value) -> Option<&'a Value> {
+        self.tokens
+            .iter()
+            .try_fold(value, |value, token| match value {
+                Value::Object(members) => members.get(token),
+                Value::Array(items) => items.get(parse_index(token)?),
+                _ => None,
+            })
+    }
+
+    /// Escapes `~` and `/` in a reference token.
+    pub fn escape(token: &str) -> Cow<'_, str> {
+        if token.contains(['~', '/']) {
+            Cow::Owned(token.replace('~', "~0").replace('/', "~1"))
+        } else {
+            Cow::Borrowed(token)
+        }
+    }
+
+    /// Unescapes `~0` and `~1` in a reference token.
+    pub fn unescape(token: &str) -> Result<Cow<'_, str>, ParseJsonPointerError> {
+        if !token.contains('~') {
+            return Ok(Cow::Borrowed(token));
+        }
+
+        let mut unescaped = String::with_capacity(token.len());
+        let mut chars = token.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '~' => match chars.next() {
+                    Some('0') => unescaped.push('~'),
+                    Some('1') => unescaped.push('/'),
+                    _ => return Err(ParseJsonPointerError::InvalidEscape),
+                },
+                c => unescaped.push(c),
+            }
+        }
+        Ok(Cow::Owned(unescaped))
+    }
+}
+
+/// The array index of a reference token, without leading zeros.
+fn parse_index(token: &str) -> Option<usize> {
+    if token.len() > 1 && token.starts_with('0') || !token.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    token.parse().ok()
+}
+
+impl fmt::Display for JsonPointer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for token in &self.tokens {
+            write!(f, "/{}", Self::escape(token))?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for JsonPointer {
+    type Err = ParseJsonPointerError;
+
+    fn from_str(pointer: &str) -> Result<Self, Self::Err> {
+        if pointer.is_empty() {
+            return Ok(Self::root());
+        }
+        let Some(pointer) = pointer.strip_prefix('/') else {
+            return Err(ParseJsonPointerError::MissingSlash);
+        };
+
+        let tokens = pointer
+            .split('/')
+            .map(|token| Self::unescape(token).map(Cow::into_owned))
+            .collect::<Result<_, _>>()?;
+        Ok(Self { tokens })
+    }
+}
+
+impl serde::Serialize for JsonPointer {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for JsonPointer {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let pointer = Cow::<str>::deserialize(deserializer)?;
+        pointer.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// The reasons a string is not a JSON pointer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseJsonPointerError {
+    /// A non-empty pointer does not start with `/`.
+    MissingSlash,
+    /// A `~` is not followed by `0` or `1`.
+    InvalidEscape,
+}
+
+impl fmt::Display for ParseJsonPointerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingSlash => f.write_str("a JSON pointer must start with `/`"),
+            Self::InvalidEscape => f.write_str("`~` must be followed by `0` or `1`"),
+        }
+    }
+}
+
+impl Error for ParseJsonPointerError {}
@@ -0,0 +1,129 @@
+//! Problem types declared once and shared by every occurrence.
+use std::{borrow::Cow, error::Error, fmt};
+
+use http::StatusCode;
+
+use crate::ProblemDetails;
+
+/// A kind of problem, identified by its `type` URI.
+///
+/// Problem types are meant to be declared as constants and collected in a
+/// [`ProblemTypeRegistry`]:
+///
+/// ```
+/// use http::StatusCode;
+/// use krabby_details::ProblemType;
+///
+/// const OUT_OF_CREDIT: ProblemType = ProblemType::new(
+///     "https://example.com/probs/out-of-credit",
+///     "You do not have enough credit.",
+///     StatusCode::FORBIDDEN,
+/// )
+/// .description("The account balance does not cover the cost of the operation.");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ProblemType {
+    pub uri: &'static str,
+    pub title: &'static str,
+    pub status: StatusCode,
+    /// A longer, human readable explanation of when the problem occurs.
+    pub description: &'static str,
+}
+
+impl ProblemType {
+    pub const fn new(uri: &'static str, title: &'static str, status: StatusCode) -> Self {
+        Self {
+            uri,
+            title,
+            status,
+            description: "",
+        }
+    }
+
+    pub const fn description(mut self, description: &'static str) -> Self {
+        self.description = description;
+        self
+    }
+}
+
+impl<Extension> ProblemDetails<Extension> {
+    /// Starts a problem of the given type, with its status and title.
+    pub fn from_type(problem_type: &ProblemType) -> Self {
+        Self {
+            type_: Cow::Borrowed(problem_type.uri),
+            status: Some(problem_type.status.as_u16()),
+            title: Some(Cow::Borrowed(problem_type.title)),
+            ..Self::default()
+        }
+    }
+}
+
+/// The problem types of an application, in registration order.
+#[derive(Clone, Debug, Default)]
+pub struct ProblemTypeRegistry {
+    types: Vec<ProblemType>,
+}
+
+impl ProblemTypeRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a problem type, failing if its URI is already registered.
+    pub fn register(&mut self, problem_type: ProblemType) -> Result<(), DuplicateProblemType> {
+        if self.get(problem_type.uri).is_some() {
+            return Err(DuplicateProblemType {
+                uri: problem_type.uri,
+            });
+        }
+
+        self.types.push(problem_type);
+        Ok(())
+    }
+
+    /// The registered problem type with the given URI.
+    pub fn get(&self, uri: &str) -> Option<&ProblemType> {
+        self.types
+            .iter()
+            .find(|problem_type| problem_type.uri == uri)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &ProblemType> {
+        self.types.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a ProblemTypeRegistry {
+    type Item = &'a ProblemType;
+    type IntoIter = std::slice::Iter<'a, ProblemType>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.types.iter()
+    }
+}
+
+impl TryFrom<Vec<ProblemType>> for ProblemTypeRegistry {
+    type Error = DuplicateProblemType;
+
+    fn try_from(types: Vec<ProblemType>) -> Result<Self, Self::Error> {
+        let mut registry = Self::new();
+        for problem_type in types {
+            registry.register(problem_type)?;
+        }
+        Ok(registry)
+    }
+}
+
+/// A problem type URI was registered twice.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicateProblemType {
+    pub uri: &'static str,
+}
+
+impl fmt::Display for DuplicateProblemType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "problem type `{}` is already registered", self.uri)
+    }
+}
+
+impl Error for DuplicateProblemType {}
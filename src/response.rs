@@ -0,0 +1,67 @@
+//! Framework neutral responses, which the framework integrations build on.
+use bytes::{BufMut, Bytes, BytesMut};
+use http::{
+    HeaderMap, HeaderName, Response,
+    header::{CONNECTION, CONTENT_ENCODING, CONTENT_LENGTH, CONTENT_TYPE, TRANSFER_ENCODING},
+};
+
+use crate::{APPLICATION_PROBLEM_JSON, ProblemDetails, internal_server_error};
+
+/// The headers describing a body and how it is framed, which must not be
+/// carried over to a response with another body.
+pub(crate) const BODY_HEADERS: [HeaderName; 5] = [
+    CONTENT_TYPE,
+    CONTENT_LENGTH,
+    CONTENT_ENCODING,
+    TRANSFER_ENCODING,
+    CONNECTION,
+];
+
+impl<Extension> ProblemDetails<Extension>
+where
+    Extension: serde::Serialize,
+{
+    /// The `application/problem+json` response sending the problem, with its
+    /// [`status_code`](Self::status_code) and [`headers`](Self::headers).
+    ///
+    /// A problem that fails to serialize is replaced by the
+    /// [`fallback_problem`](crate::fallback_problem).
+    pub fn to_http_response(&self) -> Response<Bytes> {
+        self.http_response(self.headers.clone())
+    }
+
+    /// Like [`to_http_response`](Self::to_http_response), moving the headers
+    /// out of the problem instead of cloning them.
+    pub fn into_http_response(mut self) -> Response<Bytes> {
+        let headers = std::mem::take(&mut self.headers);
+        self.http_response(headers)
+    }
+
+    /// The response with the given headers instead of the problem ones.
+    fn http_response(&self, headers: HeaderMap) -> Response<Bytes> {
+        // Use a small initial capacity of 128 bytes like serde_json::to_vec
+        // https://docs.rs/serde_json/1.0.82/src/serde_json/ser.rs.html#2189
+        let mut buf = BytesMut::with_capacity(128).writer();
+        if serde_json::to_writer(&mut buf, self).is_err() {
+            return fallback_response();
+        }
+
+        let mut response = Response::new(buf.into_inner().freeze());
+        *response.status_mut() = self.status_code();
+        *response.headers_mut() = headers;
+        response
+            .headers_mut()
+            .insert(CONTENT_TYPE, APPLICATION_PROBLEM_JSON);
+        response
+    }
+}
+
+/// The [`internal_server_error`] as a response.
+pub(crate) fn fallback_response() -> Response<Bytes> {
+    let (status, headers, body) = internal_server_error();
+
+    let mut response = Response::new(body);
+    *response.status_mut() = status;
+    response.headers_mut().extend(headers);
+    response
+}
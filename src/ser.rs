@@ -0,0 +1,372 @@
+//! Serialization of a [`ProblemDetails`], keeping extension members from
+//! colliding with the standard ones.
+use std::borrow::Cow;
+
+use serde::ser::{
+    Error, Impossible, Serialize, SerializeMap, SerializeStruct, SerializeStructVariant,
+    SerializeTupleVariant, Serializer,
+};
+use serde_json::Value;
+
+use crate::ProblemDetails;
+
+/// The members defined by RFC 9457 that extensions must not redefine.
+pub const RESERVED_MEMBERS: [&str; 5] = ["type", "status", "title", "detail", "instance"];
+
+/// What to do with an extension member named like one of the
+/// [`RESERVED_MEMBERS`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ReservedMemberPolicy {
+    /// Fail serialization, so the problem is replaced by the
+    /// [`fallback_problem`](crate::fallback_problem) when sent.
+    #[default]
+    Reject,
+    /// Serialize the member with the given prefix, e.g. `ext_status`.
+    Prefix(&'static str),
+    /// Leave the member out.
+    Drop,
+}
+
+impl ReservedMemberPolicy {
+    /// The name to serialize an extension member with, or `None` to drop it.
+    fn member_name<'a, E: Error>(self, name: &'a str) -> Result<Option<Cow<'a, str>>, E> {
+        if !RESERVED_MEMBERS.contains(&name) {
+            return Ok(Some(Cow::Borrowed(name)));
+        }
+
+        match self {
+            Self::Reject => Err(E::custom(format_args!(
+                "extension member `{name}` collides with a standard member"
+            ))),
+            Self::Prefix(prefix) => Ok(Some(Cow::Owned(format!("{prefix}{name}")))),
+            Self::Drop => Ok(None),
+        }
+    }
+}
+
+impl<Extension> Serialize for ProblemDetails<Extension>
+where
+    Extension: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", &self.type_)?;
+        // The member must match the status the problem is sent with.
+        if self.status.is_some() {
+            map.serialize_entry("status", &self.status_code().as_u16())?;
+        }
+        if let Some(title) = &self.title {
+            map.serialize_entry("title", title)?;
+        }
+        if let Some(detail) = &self.detail {
+            map.serialize_entry("detail", detail)?;
+        }
+        if let Some(instance) = &self.instance {
+            map.serialize_entry("instance", instance)?;
+        }
+        if let Some(extensions) = &self.extensions {
+            extensions.serialize(ExtensionSerializer {
+                map: &mut map,
+                policy: self.reserved_members,
+                key: None,
+            })?;
+        }
+        map.end()
+    }
+}
+
+/// Flattens the extension members into the problem map, like
+/// `#[serde(flatten)]` does, applying the [`ReservedMemberPolicy`].
+struct ExtensionSerializer<'a, M> {
+    map: &'a mut M,
+    policy: ReservedMemberPolicy,
+    /// The name of the map entry whose value is serialized next, `None` when
+    /// it is dropped.
+    key: Option<String>,
+}
+
+impl<'a, M: SerializeMap> ExtensionSerializer<'a, M> {
+    /// Starts the member named after an enum variant, whose value is buffered
+    /// until the variant ends.
+    fn variant<T: Default>(self, variant: &str) -> Result<VariantSerializer<'a, M, T>, M::Error> {
+        Ok(VariantSerializer {
+            map: self.map,
+            key: self.policy.member_name(variant)?.map(Cow::into_owned),
+            value: T::default(),
+        })
+    }
+
+    fn unsupported(kind: &str) -> M::Error {
+        M::Error::custom(format_args!(
+            "extension members must be a map or a struct, not {kind}"
+        ))
+    }
+}
+
+impl<'a, M: SerializeMap> Serializer for ExtensionSerializer<'a, M> {
+    type Ok = ();
+    type Error = M::Error;
+    type SerializeSeq = Impossible<(), M::Error>;
+    type SerializeTuple = Impossible<(), M::Error>;
+    type SerializeTupleStruct = Impossible<(), M::Error>;
+    type SerializeTupleVariant = VariantSerializer<'a, M, Vec<Value>>;
+    type SerializeMap = Self;
+    type SerializeStruct = Self;
+    type SerializeStructVariant = VariantSerializer<'a, M, Fields>;
+
+    fn serialize_bool(self, _: bool) -> Result<(), M::Error> {
+        Err(Self::unsupported("a boolean"))
+    }
+
+    fn serialize_i8(self, _: i8) -> Result<(), M::Error> {
+        Err(Self::unsupported("an integer"))
+    }
+
+    fn serialize_i16(self, _: i16) -> Result<(), M::Error> {
+        Err(Self::unsupported("an integer"))
+    }
+
+    fn serialize_i32(self, _: i32) -> Result<(), M::Error> {
+        Err(Self::unsupported("an integer"))
+    }
+
+    fn serialize_i64(self, _: i64) -> Result<(), M::Error> {
+        Err(Self::unsupported("an integer"))
+    }
+
+    fn serialize_u8(self, _: u8) -> Result<(), M::Error> {
+        Err(Self::unsupported("an integer"))
+    }
+
+    fn serialize_u16(self, _: u16) -> Result<(), M::Error> {
+        Err(Self::unsupported("an integer"))
+    }
+
+    fn serialize_u32(self, _: u32) -> Result<(), M::Error> {
+        Err(Self::unsupported("an integer"))
+    }
+
+    fn serialize_u64(self, _: u64) -> Result<(), M::Error> {
+        Err(Self::unsupported("an integer"))
+    }
+
+    fn serialize_f32(self, _: f32) -> Result<(), M::Error> {
+        Err(Self::unsupported("a float"))
+    }
+
+    fn serialize_f64(self, _: f64) -> Result<(), M::Error> {
+        Err(Self::unsupported("a float"))
+    }
+
+    fn serialize_char(self, _: char) -> Result<(), M::Error> {
+        Err(Self::unsupported("a char"))
+    }
+
+    fn serialize_str(self, _: &str) -> Result<(), M::Error> {
+        Err(Self::unsupported("a string"))
+    }
+
+    fn serialize_bytes(self, _: &[u8]) -> Result<(), M::Error> {
+        Err(Self::unsupported("bytes"))
+    }
+
+    fn serialize_none(self) -> Result<(), M::Error> {
+        Ok(())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<(), M::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<(), M::Error> {
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> Result<(), M::Error> {
+        Ok(())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+    ) -> Result<(), M::Error> {
+        self.serialize_newtype_variant("", 0, variant, &())
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<(), M::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<(), M::Error> {
+        match self.policy.member_name(variant)? {
+            Some(name) => self.map.serialize_entry(&name, value),
+            None => Ok(()),
+        }
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq, M::Error> {
+        Err(Self::unsupported("a sequence"))
+    }
+
+    fn serialize_tuple(self, _: usize) -> Result<Self::SerializeTuple, M::Error> {
+        Err(Self::unsupported("a tuple"))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleStruct, M::Error> {
+        Err(Self::unsupported("a tuple struct"))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleVariant, M::Error> {
+        self.variant(variant)
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<Self, M::Error> {
+        Ok(self)
+    }
+
+    fn serialize_struct(self, _: &'static str, _: usize) -> Result<Self, M::Error> {
+        Ok(self)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStructVariant, M::Error> {
+        self.variant(variant)
+    }
+}
+
+impl<M: SerializeMap> SerializeMap for ExtensionSerializer<'_, M> {
+    type Ok = ();
+    type Error = M::Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), M::Error> {
+        let key = match key.serialize(serde_json::value::Serializer) {
+            Ok(serde_json::Value::String(key)) => key,
+            Ok(serde_json::Value::Number(key)) => key.to_string(),
+            _ => return Err(M::Error::custom("extension member names must be strings")),
+        };
+        self.key = self.policy.member_name(&key)?.map(|name| name.into_owned());
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), M::Error> {
+        match self.key.take() {
+            Some(key) => self.map.serialize_entry(&key, value),
+            None => Ok(()),
+        }
+    }
+
+    fn end(self) -> Result<(), M::Error> {
+        Ok(())
+    }
+}
+
+impl<M: SerializeMap> SerializeStruct for ExtensionSerializer<'_, M> {
+    type Ok = ();
+    type Error = M::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), M::Error> {
+        match self.policy.member_name(key)? {
+            Some(name) => self.map.serialize_entry(&name, value),
+            None => Ok(()),
+        }
+    }
+
+    fn end(self) -> Result<(), M::Error> {
+        Ok(())
+    }
+}
+
+/// Serializes a tuple or struct variant as the value of the member named after
+/// the variant, like `#[serde(flatten)]` does for externally tagged enums.
+struct VariantSerializer<'a, M, T> {
+    map: &'a mut M,
+    /// The name of the member, `None` when it is dropped.
+    key: Option<String>,
+    value: T,
+}
+
+impl<M: SerializeMap, T: Serialize> VariantSerializer<'_, M, T> {
+    fn end(self) -> Result<(), M::Error> {
+        match self.key {
+            Some(key) => self.map.serialize_entry(&key, &self.value),
+            None => Ok(()),
+        }
+    }
+}
+
+/// The fields of a struct variant, in declaration order.
+#[derive(Default)]
+struct Fields(Vec<(&'static str, Value)>);
+
+impl Serialize for Fields {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.0.iter().map(|(key, value)| (key, value)))
+    }
+}
+
+fn to_value<T: Serialize + ?Sized, E: Error>(value: &T) -> Result<Value, E> {
+    serde_json::to_value(value).map_err(E::custom)
+}
+
+impl<M: SerializeMap> SerializeTupleVariant for VariantSerializer<'_, M, Vec<Value>> {
+    type Ok = ();
+    type Error = M::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), M::Error> {
+        self.value.push(to_value(value)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<(), M::Error> {
+        VariantSerializer::end(self)
+    }
+}
+
+impl<M: SerializeMap> SerializeStructVariant for VariantSerializer<'_, M, Fields> {
+    type Ok = ();
+    type Error = M::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), M::Error> {
+        self.value.0.push((key, to_value(value)?));
+        Ok(())
+    }
+
+    fn end(self) -> Result<(), M::Error> {
+        VariantSerializer::end(self)
+    }
+}
@@ -0,0 +1,103 @@
+//! Conversion of the errors of the [`validator`] crate.
+use std::borrow::Cow;
+
+use http::StatusCode;
+
+use crate::{JsonPointer, ProblemDetails, Source, ValidationError, ValidationErrors};
+
+/// One validation error per failed constraint, pointing at the invalid
+/// field. The validator `code` and `params` are kept, but not the `value`
+/// parameter, which holds the raw input, e.g. a password. See
+/// [`ValidationErrors::with_rejected_values`] to keep it.
+impl From<validator::ValidationErrors> for ValidationErrors {
+    fn from(errors: validator::ValidationErrors) -> Self {
+        let mut errors = Self::with_rejected_values(errors);
+        errors.redact_rejected_values();
+        errors
+    }
+}
+
+impl ValidationErrors {
+    /// Like the `From` conversion, with the validator `value` parameter as
+    /// the rejected value.
+    ///
+    /// Only use it when none of the validated fields is sensitive.
+    pub fn with_rejected_values(errors: validator::ValidationErrors) -> Self {
+        let mut collected = Vec::new();
+        collect(errors, &mut JsonPointer::root(), &mut collected);
+        Self { errors: collected }
+    }
+}
+
+/// An `422 Unprocessable Entity` problem with the validation errors.
+impl From<validator::ValidationErrors> for ProblemDetails<ValidationErrors> {
+    fn from(errors: validator::ValidationErrors) -> Self {
+        ProblemDetails::new(StatusCode::UNPROCESSABLE_ENTITY)
+            .detail("The request failed validation")
+            .extension(errors.into())
+    }
+}
+
+fn collect(
+    errors: validator::ValidationErrors,
+    pointer: &mut JsonPointer,
+    collected: &mut Vec<ValidationError>,
+) {
+    // Sort the fields, as the validator errors are kept in a hash map.
+    let mut fields = errors.into_errors().into_iter().collect::<Vec<_>>();
+    fields.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    for (field, kind) in fields {
+        // Struct level validation errors belong to the struct itself.
+        let is_struct_level = field == "__all__";
+        if !is_struct_level {
+            pointer.push_key(field.as_ref());
+        }
+
+        match kind {
+            validator::ValidationErrorsKind::Struct(errors) => collect(*errors, pointer, collected),
+            validator::ValidationErrorsKind::List(items) => {
+                for (index, errors) in items {
+                    pointer.push_index(index);
+                    collect(*errors, pointer, collected);
+                    pointer.pop();
+                }
+            }
+            validator::ValidationErrorsKind::Field(errors) => {
+                collected.extend(
+                    errors
+                        .into_iter()
+                        .map(|error| validation_error(error, pointer.clone())),
+                );
+            }
+        }
+
+        if !is_struct_level {
+            pointer.pop();
+        }
+    }
+}
+
+fn validation_error(error: validator::ValidationError, pointer: JsonPointer) -> ValidationError {
+    let detail = match error.message {
+        Some(message) => message.into_owned(),
+        None => format!("failed the `{}` validation", error.code),
+    };
+
+    let mut params = error.params;
+    let rejected_value = params.remove("value");
+
+    let mut validation_error = ValidationError::new(
+        detail,
+        Source::Body {
+            pointer: Some(pointer),
+        },
+    )
+    .code(error.code);
+    validation_error.params = params
+        .into_iter()
+        .map(|(name, value)| (Cow::into_owned(name), value))
+        .collect();
+    validation_error.rejected_value = rejected_value;
+    validation_error
+}
@@ -0,0 +1,53 @@
+//! Replies and rejections for warp.
+use std::fmt::Debug;
+
+use http::StatusCode;
+use serde::Serialize;
+use warp::{
+    Rejection,
+    reject::Reject,
+    reply::{Reply, Response},
+};
+
+use crate::ProblemDetails;
+
+/// Sends the problem like its axum `IntoResponse` does.
+impl<Extension> Reply for ProblemDetails<Extension>
+where
+    Extension: Serialize + Send,
+{
+    fn into_response(self) -> Response {
+        self.into_http_response().map(Into::into)
+    }
+}
+
+/// Lets filters reject with a problem, sent by [`recover_problem`].
+impl<Extension> Reject for ProblemDetails<Extension> where Extension: Debug + Send + Sync + 'static {}
+
+/// Recovers from the rejections carrying a [`ProblemDetails`], and from the
+/// `404 Not Found` of unmatched routes, by sending a problem.
+///
+/// Other rejections are passed on, so they can be recovered from by the next
+/// handler:
+///
+/// ```
+/// use krabby_details::{ValidationErrors, recover_problem};
+/// use warp::Filter;
+///
+/// let routes = warp::path("users")
+///     .map(warp::reply)
+///     .recover(recover_problem::<ValidationErrors>);
+/// ```
+pub async fn recover_problem<Extension>(rejection: Rejection) -> Result<Response, Rejection>
+where
+    Extension: Serialize + Debug + Send + Sync + 'static,
+{
+    if let Some(problem) = rejection.find::<ProblemDetails<Extension>>() {
+        return Ok(problem.to_http_response().map(Into::into));
+    }
+    if rejection.is_not_found() {
+        return Ok(ProblemDetails::<()>::new(StatusCode::NOT_FOUND).into_response());
+    }
+
+    Err(rejection)
+}
@@ -0,0 +1,176 @@
+//! The `application/problem+xml` format of
+//! [RFC 9457 Appendix B](https://www.rfc-editor.org/rfc/rfc9457.html#appendix-B).
+use std::{error::Error, fmt, fmt::Write};
+
+#[cfg(feature = "axum")]
+use axum_core::response::{IntoResponse, Response};
+use http::HeaderValue;
+#[cfg(feature = "axum")]
+use http::header::CONTENT_TYPE;
+use serde_json::{Map, Value};
+
+#[cfg(feature = "axum")]
+use crate::internal_server_error;
+use crate::{ProblemDetails, RESERVED_MEMBERS};
+
+pub const APPLICATION_PROBLEM_XML: HeaderValue =
+    HeaderValue::from_static("application/problem+xml");
+
+/// The namespace of the `problem` root element.
+pub const PROBLEM_XML_NAMESPACE: &str = "urn:ietf:rfc:7807";
+
+impl<Extension> ProblemDetails<Extension>
+where
+    Extension: serde::Serialize,
+{
+    /// Serializes the problem as `application/problem+xml`.
+    ///
+    /// The standard members come first, followed by the extension members in
+    /// alphabetical order. Arrays are written as a sequence of `i` elements.
+    pub fn to_xml(&self) -> Result<String, XmlError> {
+        let Value::Object(mut members) = serde_json::to_value(self).map_err(XmlError::Json)? else {
+            unreachable!("a problem always serializes to a map");
+        };
+
+        let mut xml = String::with_capacity(256);
+        xml.push_str(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+        write!(xml, r#"<problem xmlns="{PROBLEM_XML_NAMESPACE}">"#).unwrap();
+        for name in RESERVED_MEMBERS {
+            if let Some(value) = members.remove(name) {
+                write_element(&mut xml, name, &value)?;
+            }
+        }
+        write_members(&mut xml, &members)?;
+        xml.push_str("</problem>");
+
+        Ok(xml)
+    }
+}
+
+fn write_members(xml: &mut String, members: &Map<String, Value>) -> Result<(), XmlError> {
+    // Sorted here, as the map keeps the insertion order with the
+    // `preserve_order` feature of serde_json.
+    let mut members = members.iter().collect::<Vec<_>>();
+    members.sort_unstable_by_key(|(name, _)| *name);
+
+    for (name, value) in members {
+        write_element(xml, name, value)?;
+    }
+    Ok(())
+}
+
+fn write_element(xml: &mut String, name: &str, value: &Value) -> Result<(), XmlError> {
+    if !is_xml_name(name) {
+        return Err(XmlError::InvalidName(name.to_owned()));
+    }
+
+    match value {
+        Value::Null => write!(xml, "<{name}/>").unwrap(),
+        Value::Bool(value) => write!(xml, "<{name}>{value}</{name}>").unwrap(),
+        Value::Number(value) => write!(xml, "<{name}>{value}</{name}>").unwrap(),
+        Value::String(value) => write!(xml, "<{name}>{}</{name}>", escape(value)?).unwrap(),
+        Value::Array(items) => {
+            write!(xml, "<{name}>").unwrap();
+            for item in items {
+                write_element(xml, "i", item)?;
+            }
+            write!(xml, "</{name}>").unwrap();
+        }
+        Value::Object(members) => {
+            write!(xml, "<{name}>").unwrap();
+            write_members(xml, members)?;
+            write!(xml, "</{name}>").unwrap();
+        }
+    }
+    Ok(())
+}
+
+/// Whether the member name can be used as an element name, restricted to
+/// names without a namespace prefix.
+fn is_xml_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    let Some(first) = chars.next() else {
+        return false;
+    };
+
+    (first.is_alphabetic() || first == '_')
+        && !name
+            .get(..3)
+            .is_some_and(|prefix| prefix.eq_ignore_ascii_case("xml"))
+        && chars.all(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | '.'))
+}
+
+fn escape(text: &str) -> Result<String, XmlError> {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            c if !is_xml_char(c) => return Err(XmlError::InvalidChar(c)),
+            c => escaped.push(c),
+        }
+    }
+    Ok(escaped)
+}
+
+/// Whether the character is allowed in an XML 1.0 document, even escaped.
+fn is_xml_char(c: char) -> bool {
+    matches!(c, '\t' | '\n' | '\r' | '\u{20}'..='\u{D7FF}' | '\u{E000}'..='\u{FFFD}' | '\u{10000}'..)
+}
+
+/// Sends a [`ProblemDetails`] as `application/problem+xml`.
+#[cfg(feature = "axum")]
+#[derive(Debug)]
+pub struct ProblemXml<Extension>(pub ProblemDetails<Extension>);
+
+#[cfg(feature = "axum")]
+impl<Extension> IntoResponse for ProblemXml<Extension>
+where
+    Extension: serde::Serialize,
+{
+    fn into_response(self) -> Response {
+        let Self(mut problem) = self;
+        match problem.to_xml() {
+            Ok(xml) => (
+                problem.status_code(),
+                std::mem::take(&mut problem.headers),
+                [(CONTENT_TYPE, APPLICATION_PROBLEM_XML)],
+                xml,
+            )
+                .into_response(),
+            Err(_) => internal_server_error().into_response(),
+        }
+    }
+}
+
+/// The reasons a problem could not be serialized as XML.
+#[derive(Debug)]
+pub enum XmlError {
+    /// The problem failed to serialize.
+    Json(serde_json::Error),
+    /// A member name is not a valid XML element name.
+    InvalidName(String),
+    /// A string contains a character that XML does not allow, such as most
+    /// control characters.
+    InvalidChar(char),
+}
+
+impl fmt::Display for XmlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Json(_) => f.write_str("the problem failed to serialize"),
+            Self::InvalidName(name) => write!(f, "`{name}` is not a valid XML element name"),
+            Self::InvalidChar(c) => write!(f, "{c:?} is not allowed in XML"),
+        }
+    }
+}
+
+impl Error for XmlError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Json(error) => Some(error),
+            Self::InvalidName(_) | Self::InvalidChar(_) => None,
+        }
+    }
+}
@@ -0,0 +1,72 @@
+#![cfg(feature = "actix")]
+
+use actix_web::{
+    App, HttpResponse, Responder, ResponseError, body,
+    http::header,
+    test::{TestRequest, call_service, init_service},
+    web,
+};
+use http::{HeaderName, HeaderValue, StatusCode};
+use krabby_details::{ProblemDetails, fallback_problem};
+use serde_json::{Value, json};
+
+async fn body_json(response: HttpResponse) -> Value {
+    let bytes = body::to_bytes(response.into_body()).await.unwrap();
+    serde_json::from_slice(&bytes).unwrap()
+}
+
+#[actix_web::test]
+async fn responds_like_the_axum_integration() {
+    let problem = ProblemDetails::<()>::new(StatusCode::TOO_MANY_REQUESTS)
+        .detail("Slow down")
+        .header(
+            HeaderName::from_static("retry-after"),
+            HeaderValue::from_static("60"),
+        );
+    let response = problem.respond_to(&TestRequest::default().to_http_request());
+
+    assert_eq!(response.status().as_u16(), 429);
+    assert_eq!(
+        response.headers().get(header::CONTENT_TYPE).unwrap(),
+        "application/problem+json"
+    );
+    assert_eq!(response.headers().get("retry-after").unwrap(), "60");
+    assert_eq!(
+        body_json(response).await,
+        json!({
+            "type": "about:blank",
+            "status": 429,
+            "title": "Too Many Requests",
+            "detail": "Slow down",
+        })
+    );
+}
+
+#[actix_web::test]
+async fn handlers_can_fail_with_a_problem() {
+    let app = init_service(App::new().route(
+        "/",
+        web::get().to(|| async {
+            Err::<HttpResponse, _>(ProblemDetails::<()>::new(StatusCode::NOT_FOUND))
+        }),
+    ))
+    .await;
+    let response = call_service(&app, TestRequest::get().uri("/").to_request()).await;
+
+    assert_eq!(response.status().as_u16(), 404);
+    let body = body::to_bytes(response.into_body()).await.unwrap();
+    assert_eq!(
+        serde_json::from_slice::<Value>(&body).unwrap()["status"],
+        404
+    );
+}
+
+#[actix_web::test]
+async fn unserializable_problems_send_the_fallback() {
+    let problem = ProblemDetails::<Value>::new(StatusCode::BAD_REQUEST).extension(json!(42));
+    let response = problem.error_response();
+
+    assert_eq!(response.status().as_u16(), 500);
+    let body = body::to_bytes(response.into_body()).await.unwrap();
+    assert_eq!(body, fallback_problem());
+}
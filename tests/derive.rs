@@ -0,0 +1,80 @@
+#![cfg(feature = "derive")]
+
+use http::StatusCode;
+use krabby_details::{DynamicExtensions, Problem, ProblemDetails};
+use serde_json::json;
+
+#[derive(Problem)]
+enum AccountError {
+    #[problem(
+        status = 403,
+        type = "https://example.com/probs/out-of-credit",
+        title = "You do not have enough credit.",
+        detail = "Your current balance is {balance}, but that costs {cost}."
+    )]
+    OutOfCredit {
+        #[problem(extension)]
+        balance: u32,
+        cost: u32,
+    },
+    #[problem(status = 404, detail = "The account does not exist.")]
+    NotFound(#[problem(extension = "account")] &'static str),
+    Unexpected,
+}
+
+fn to_json(error: AccountError) -> serde_json::Value {
+    serde_json::to_value(ProblemDetails::<DynamicExtensions>::from(error)).unwrap()
+}
+
+#[test]
+fn struct_variant_formats_members_and_adds_extensions() {
+    assert_eq!(
+        to_json(AccountError::OutOfCredit {
+            balance: 30,
+            cost: 50
+        }),
+        json!({
+            "type": "https://example.com/probs/out-of-credit",
+            "status": 403,
+            "title": "You do not have enough credit.",
+            "detail": "Your current balance is 30, but that costs 50.",
+            "balance": 30,
+        })
+    );
+}
+
+#[test]
+fn tuple_variant_defaults_title_to_canonical_reason() {
+    assert_eq!(
+        to_json(AccountError::NotFound("/account/12345")),
+        json!({
+            "type": "about:blank",
+            "status": 404,
+            "title": "Not Found",
+            "detail": "The account does not exist.",
+            "account": "/account/12345",
+        })
+    );
+}
+
+#[test]
+fn unit_variant_defaults_to_internal_server_error() {
+    let problem = ProblemDetails::from(AccountError::Unexpected);
+
+    assert_eq!(problem.status_code(), StatusCode::INTERNAL_SERVER_ERROR);
+}
+
+#[cfg(feature = "axum")]
+#[test]
+fn implements_into_response() {
+    use axum_core::response::IntoResponse;
+
+    let response = AccountError::Unexpected.into_response();
+
+    assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+}
+
+#[test]
+fn rejects_reserved_extension_names() {
+    trybuild::TestCases::new().compile_fail("tests/ui/reserved_extension*.rs");
+}
@@ -0,0 +1,48 @@
+use http::StatusCode;
+use krabby_details::{JsonPointer, Source, from_json_slice};
+
+#[derive(serde::Deserialize, Debug)]
+#[allow(dead_code)]
+struct Order {
+    items: Vec<Item>,
+}
+
+#[derive(serde::Deserialize, Debug)]
+#[allow(dead_code)]
+struct Item {
+    quantity: u32,
+}
+
+#[test]
+fn data_error_points_at_the_offending_value() {
+    let problem =
+        from_json_slice::<Order>(br#"{"items":[{"quantity":1},{"quantity":"two"}]}"#).unwrap_err();
+    let errors = problem.extensions.unwrap().errors;
+
+    assert_eq!(problem.status, Some(422));
+    assert!(matches!(
+        &errors[0].source,
+        Source::Body { pointer: Some(pointer) }
+            if *pointer == JsonPointer::root().with_key("items").with_index(1).with_key("quantity")
+    ));
+}
+
+#[test]
+fn syntax_error_is_a_bad_request() {
+    for body in [&br#"{"items":["#[..], br#"{"items":[]} trailing"#] {
+        let problem = from_json_slice::<Order>(body).unwrap_err();
+
+        assert_eq!(problem.status_code(), StatusCode::BAD_REQUEST);
+        assert!(matches!(
+            problem.extensions.unwrap().errors[0].source,
+            Source::Body { pointer: None }
+        ));
+    }
+}
+
+#[test]
+fn valid_body_deserializes() {
+    let order = from_json_slice::<Order>(br#"{"items":[{"quantity":3}]}"#).unwrap();
+
+    assert_eq!(order.items[0].quantity, 3);
+}
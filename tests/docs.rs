@@ -0,0 +1,111 @@
+#![cfg(feature = "docs")]
+
+use axum::{Router, body::Body};
+use http::{
+    Request, StatusCode,
+    header::{ACCEPT, VARY},
+};
+use http_body_util::BodyExt;
+use krabby_details::{ProblemType, ProblemTypeRegistry};
+use tower::ServiceExt;
+
+fn router() -> Router {
+    ProblemTypeRegistry::try_from(vec![
+        ProblemType::new(
+            "https://example.com/probs/out-of-credit",
+            "You do not have <enough> credit.",
+            StatusCode::FORBIDDEN,
+        ),
+        ProblemType::new("about:blank", "Blank", StatusCode::BAD_REQUEST),
+    ])
+    .unwrap()
+    .docs_router()
+}
+
+async fn get(path: &str, accept: &str) -> (StatusCode, String) {
+    let request = Request::get(path)
+        .header(ACCEPT, accept)
+        .body(Body::empty())
+        .unwrap();
+    let response = router().oneshot(request).await.unwrap();
+    let status = response.status();
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+
+    (status, String::from_utf8(body.to_vec()).unwrap())
+}
+
+#[tokio::test]
+async fn serves_escaped_html_page() {
+    let (status, body) = get("/probs/out-of-credit", "text/html").await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert!(body.contains("<h1>You do not have &lt;enough&gt; credit.</h1>"));
+}
+
+#[tokio::test]
+async fn serves_json_page() {
+    let (status, body) = get("/probs/out-of-credit", "application/json").await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&body).unwrap(),
+        serde_json::json!({
+            "type": "https://example.com/probs/out-of-credit",
+            "title": "You do not have <enough> credit.",
+            "status": 403,
+            "description": "",
+        })
+    );
+}
+
+#[tokio::test]
+async fn serves_html_when_json_is_not_acceptable() {
+    let (_, body) = get("/probs/out-of-credit", "application/json;q=0, text/html").await;
+    assert!(body.starts_with("<!DOCTYPE html>"));
+
+    let (_, body) = get("/probs/out-of-credit", "application/json;q=0.5, text/html").await;
+    assert!(body.starts_with("<!DOCTYPE html>"));
+}
+
+#[tokio::test]
+async fn varies_on_accept() {
+    for accept in ["application/json", "text/html"] {
+        let request = Request::get("/probs/out-of-credit")
+            .header(ACCEPT, accept)
+            .body(Body::empty())
+            .unwrap();
+        let response = router().oneshot(request).await.unwrap();
+        assert_eq!(response.headers()[VARY], "accept", "{accept}");
+    }
+}
+
+async fn status_of(problem_type: &'static str, path: &str) -> StatusCode {
+    let router: Router = ProblemTypeRegistry::try_from(vec![ProblemType::new(
+        problem_type,
+        "Unroutable",
+        StatusCode::GONE,
+    )])
+    .unwrap()
+    .docs_router();
+    let request = Request::get(path).body(Body::empty()).unwrap();
+
+    router.oneshot(request).await.unwrap().status()
+}
+
+#[tokio::test]
+async fn skips_legacy_capture_paths() {
+    let status = status_of("https://example.com/probs/:legacy", "/probs/:legacy").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn skips_templated_paths() {
+    let status = status_of("https://example.com/probs/{id}", "/probs/anything").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn skips_wildcard_paths() {
+    let status = status_of("https://example.com/probs/{*rest}", "/probs/a/b").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
@@ -0,0 +1,27 @@
+use http::StatusCode;
+use krabby_details::{DynamicExtensions, ProblemDetails};
+use serde_json::json;
+
+#[test]
+fn extensions_from_several_layers_are_merged() {
+    let mut problem = ProblemDetails::<DynamicExtensions>::new(StatusCode::FORBIDDEN)
+        .detail("Your current balance is 30, but that costs 50.");
+
+    problem.insert_extension("balance", 30);
+    problem.insert_extension("trace_id", "4bf92f3577b34da6");
+    assert_eq!(problem.insert_extension("balance", 20), Some(json!(30)));
+
+    assert_eq!(problem.get_extension("balance"), Some(&json!(20)));
+    assert_eq!(problem.remove_extension("missing"), None);
+    assert_eq!(
+        serde_json::to_value(&problem).unwrap(),
+        json!({
+            "type": "about:blank",
+            "status": 403,
+            "title": "Forbidden",
+            "detail": "Your current balance is 30, but that costs 50.",
+            "balance": 20,
+            "trace_id": "4bf92f3577b34da6",
+        })
+    );
+}
@@ -0,0 +1,101 @@
+#![cfg(feature = "axum")]
+
+use axum::{
+    Router,
+    body::Body,
+    routing::{get, post},
+};
+use http::{Request, StatusCode, header::CONTENT_TYPE};
+use http_body_util::BodyExt;
+use krabby_details::{ProblemJson, ProblemPath, ProblemQuery};
+use serde_json::{Value, json};
+use tower::ServiceExt;
+
+#[derive(serde::Deserialize)]
+#[allow(dead_code)]
+struct User {
+    name: String,
+    pets: Vec<Pet>,
+}
+
+#[derive(serde::Deserialize)]
+#[allow(dead_code)]
+struct Pet {
+    age: u32,
+}
+
+#[derive(serde::Deserialize)]
+#[allow(dead_code)]
+struct UserId {
+    id: u32,
+}
+
+#[derive(serde::Deserialize)]
+#[allow(dead_code)]
+struct Page {
+    page: u32,
+}
+
+fn router() -> Router {
+    Router::new()
+        .route("/users", post(|_: ProblemJson<User>| async {}))
+        .route("/users/{id}", get(|_: ProblemPath<UserId>| async {}))
+        .route("/pets", get(|_: ProblemQuery<Page>| async {}))
+}
+
+async fn send(request: Request<Body>) -> (StatusCode, Value) {
+    let response = router().oneshot(request).await.unwrap();
+    let status = response.status();
+    assert_eq!(response.headers()[CONTENT_TYPE], "application/problem+json");
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+
+    (status, serde_json::from_slice(&body).unwrap())
+}
+
+#[tokio::test]
+async fn json_data_error_points_at_the_field() {
+    let (status, problem) = send(
+        Request::post("/users")
+            .header(CONTENT_TYPE, "application/json")
+            .body(Body::from(r#"{"name":"Ferris","pets":[{"age":-1}]}"#))
+            .unwrap(),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(problem["status"], 422);
+    assert_eq!(problem["errors"][0]["source"], "body", "{problem:#}");
+    assert_eq!(problem["errors"][0]["pointer"], "/pets/0/age");
+}
+
+#[tokio::test]
+async fn missing_content_type_points_at_the_header() {
+    let (status, problem) = send(Request::post("/users").body(Body::empty()).unwrap()).await;
+
+    assert_eq!(status, StatusCode::UNSUPPORTED_MEDIA_TYPE);
+    assert_eq!(
+        problem["errors"][0],
+        json!({
+            "detail": "Expected request with `Content-Type: application/json`",
+            "source": "header",
+            "name": "content-type",
+        })
+    );
+}
+
+#[tokio::test]
+async fn path_error_names_the_parameter() {
+    let (status, problem) = send(Request::get("/users/abc").body(Body::empty()).unwrap()).await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(problem["errors"][0]["source"], "path");
+    assert_eq!(problem["errors"][0]["parameter"], "id");
+}
+
+#[tokio::test]
+async fn query_error_points_at_the_query() {
+    let (status, problem) = send(Request::get("/pets?page=abc").body(Body::empty()).unwrap()).await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(problem["errors"][0]["source"], "query");
+}
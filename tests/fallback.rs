@@ -0,0 +1,90 @@
+use http::{StatusCode, header::CONTENT_TYPE};
+use krabby_details::{
+    APPLICATION_PROBLEM_JSON, INTERNAL_SERVER_ERROR_PROBLEM, ProblemDetails, SetFallbackError,
+    set_fallback_problem,
+};
+
+struct Unserializable;
+
+impl serde::Serialize for Unserializable {
+    fn serialize<S: serde::Serializer>(&self, _: S) -> Result<S::Ok, S::Error> {
+        Err(serde::ser::Error::custom("unserializable"))
+    }
+}
+
+#[test]
+fn internal_server_error_problem_round_trips() {
+    let problem: ProblemDetails<()> =
+        serde_json::from_slice(&INTERNAL_SERVER_ERROR_PROBLEM).unwrap();
+
+    assert_eq!(problem.type_, "internal_server_error");
+    assert_eq!(problem.status, Some(500));
+    assert_eq!(problem.title.as_deref(), Some("Internal Server Error"));
+    assert_eq!(
+        serde_json::to_vec(&problem).unwrap(),
+        *INTERNAL_SERVER_ERROR_PROBLEM
+    );
+}
+
+#[test]
+#[allow(deprecated)]
+fn deprecated_internal_server_error_sends_the_default_problem() {
+    let (status, [(name, value)], body) = krabby_details::INTERNAL_SERVER_ERROR;
+
+    assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+    assert_eq!((name, value), (CONTENT_TYPE, APPLICATION_PROBLEM_JSON));
+    assert_eq!(body, &INTERNAL_SERVER_ERROR_PROBLEM[..]);
+}
+
+#[test]
+fn fallback_problem_must_be_an_internal_server_error() {
+    let unavailable = ProblemDetails::<()> {
+        status: Some(503),
+        ..Default::default()
+    };
+    assert!(matches!(
+        set_fallback_problem(&unavailable),
+        Err(SetFallbackError::Status(StatusCode::SERVICE_UNAVAILABLE))
+    ));
+
+    let resent = ProblemDetails::<()> {
+        status: Some(500),
+        response_status: Some(StatusCode::NOT_FOUND),
+        ..Default::default()
+    };
+    assert!(matches!(
+        set_fallback_problem(&resent),
+        Err(SetFallbackError::Status(StatusCode::NOT_FOUND))
+    ));
+}
+
+#[test]
+fn unserializable_problem_sends_configured_fallback() {
+    let fallback = ProblemDetails::<()> {
+        type_: "https://example.com/probs/unexpected".into(),
+        status: Some(500),
+        title: Some("Unexpected error".into()),
+        ..Default::default()
+    };
+    set_fallback_problem(&fallback).unwrap();
+
+    assert!(matches!(
+        set_fallback_problem(&fallback),
+        Err(SetFallbackError::AlreadySet)
+    ));
+
+    let response = ProblemDetails {
+        status: Some(404),
+        extensions: Some(Unserializable),
+        ..Default::default()
+    }
+    .to_http_response();
+
+    assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    assert_eq!(response.headers()[CONTENT_TYPE], APPLICATION_PROBLEM_JSON);
+
+    let problem: ProblemDetails<()> = serde_json::from_slice(response.body()).unwrap();
+
+    assert_eq!(problem.type_, "https://example.com/probs/unexpected");
+    assert_eq!(problem.title.as_deref(), Some("Unexpected error"));
+}
@@ -0,0 +1,89 @@
+use bytes::Bytes;
+use http::header::CONTENT_TYPE;
+use krabby_details::{FromResponseError, ProblemDetails, Source, ValidationErrors};
+
+fn response(content_type: &str, body: &'static str) -> http::Response<Bytes> {
+    http::Response::builder()
+        .status(422)
+        .header(CONTENT_TYPE, content_type)
+        .body(Bytes::from_static(body.as_bytes()))
+        .unwrap()
+}
+
+#[derive(serde::Deserialize, Debug, PartialEq)]
+struct OutOfCredit {
+    balance: u32,
+    accounts: Vec<String>,
+}
+
+#[test]
+fn parses_standard_members_and_extensions() {
+    let response = response(
+        "application/problem+json; charset=utf-8",
+        r#"{
+            "type": "https://example.com/probs/out-of-credit",
+            "title": "You do not have enough credit.",
+            "detail": "Your current balance is 30, but that costs 50.",
+            "instance": "/account/12345/msgs/abc",
+            "balance": 30,
+            "accounts": ["/account/12345", "/account/67890"]
+        }"#,
+    );
+
+    let problem = ProblemDetails::<OutOfCredit>::from_response(&response).unwrap();
+
+    assert_eq!(problem.type_, "https://example.com/probs/out-of-credit");
+    assert_eq!(problem.status, None);
+    assert_eq!(problem.instance.as_deref(), Some("/account/12345/msgs/abc"));
+    assert_eq!(
+        problem.extensions,
+        Some(OutOfCredit {
+            balance: 30,
+            accounts: vec!["/account/12345".into(), "/account/67890".into()],
+        })
+    );
+}
+
+#[test]
+fn tolerates_missing_members() {
+    let response = response("application/problem+json", "{}");
+
+    let problem = ProblemDetails::<OutOfCredit>::from_response(&response).unwrap();
+
+    assert_eq!(problem.type_, "about:blank");
+    assert_eq!(problem.title, None);
+    assert_eq!(problem.extensions, None);
+}
+
+#[test]
+fn parses_validation_errors() {
+    let response = response(
+        "application/problem+json",
+        r#"{
+            "status": 422,
+            "errors": [
+                { "detail": "must be a positive integer", "source": "body", "pointer": "/age" },
+                { "detail": "must be 'green', 'red' or 'blue'", "source": "header", "name": "X-Color" }
+            ]
+        }"#,
+    );
+
+    let problem = ProblemDetails::<ValidationErrors>::from_response(&response).unwrap();
+    let errors = problem.extensions.unwrap().errors;
+
+    assert_eq!(problem.status, Some(422));
+    assert!(
+        matches!(&errors[0].source, Source::Body { pointer: Some(pointer) } if pointer.to_string() == "/age")
+    );
+    assert!(matches!(&errors[1].source, Source::Header { name } if name == "X-Color"));
+}
+
+#[test]
+fn rejects_other_content_types() {
+    let response = response("application/json", "{}");
+
+    assert!(matches!(
+        ProblemDetails::<()>::from_response(&response),
+        Err(FromResponseError::ContentType)
+    ));
+}
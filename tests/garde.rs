@@ -0,0 +1,82 @@
+#![cfg(feature = "garde")]
+
+use garde::Validate;
+use krabby_details::{ProblemDetails, ValidationErrors};
+
+#[derive(Validate, serde::Deserialize)]
+struct SignUp {
+    #[garde(length(min = 3))]
+    name: String,
+    #[garde(dive)]
+    pets: Vec<Pet>,
+    #[garde(inner(length(min = 1)))]
+    nickname: Option<String>,
+}
+
+#[derive(Validate, serde::Deserialize)]
+struct Pet {
+    #[garde(range(max = 30))]
+    age: u32,
+}
+
+#[test]
+fn report_paths_become_body_pointers() {
+    let sign_up = SignUp {
+        name: "Fe".into(),
+        pets: vec![Pet { age: 3 }, Pet { age: 42 }],
+        nickname: Some(String::new()),
+    };
+    let problem = ProblemDetails::<ValidationErrors>::from(sign_up.validate().unwrap_err());
+
+    assert_eq!(problem.status, Some(422));
+    let pointers = problem
+        .extensions
+        .unwrap()
+        .errors
+        .iter()
+        .map(|error| serde_json::to_value(error).unwrap()["pointer"].clone())
+        .collect::<Vec<_>>();
+    assert_eq!(pointers, ["/name", "/nickname", "/pets/1/age"]);
+}
+
+#[cfg(feature = "axum")]
+#[tokio::test]
+async fn extractor_rejects_invalid_values() {
+    use axum::{Router, body::Body, routing::post};
+    use http::{Request, StatusCode, header::CONTENT_TYPE};
+    use http_body_util::BodyExt;
+    use krabby_details::ProblemValidJson;
+    use serde_json::json;
+    use tower::ServiceExt;
+
+    let router = Router::new().route("/sign-up", post(|_: ProblemValidJson<SignUp>| async {}));
+    let request = |body: &'static str| {
+        Request::post("/sign-up")
+            .header(CONTENT_TYPE, "application/json")
+            .body(Body::from(body))
+            .unwrap()
+    };
+
+    let response = router
+        .clone()
+        .oneshot(request(r#"{"name":"Ferris","pets":[]}"#))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = router
+        .oneshot(request(r#"{"name":"Fe","pets":[]}"#))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let problem = serde_json::from_slice::<serde_json::Value>(&body).unwrap();
+    assert_eq!(
+        problem["errors"],
+        json!([{
+            "detail": "length is lower than 3",
+            "source": "body",
+            "pointer": "/name",
+        }])
+    );
+}
@@ -0,0 +1,76 @@
+use http::StatusCode;
+use krabby_details::{
+    HtmlPage, JsonPointer, ProblemDetails, Source, ValidationError, ValidationErrors,
+    set_html_template,
+};
+
+#[test]
+fn escapes_every_member() {
+    let html = ProblemDetails::<()>::new(StatusCode::NOT_FOUND)
+        .title("<b>Gone</b>")
+        .detail("<script>alert('x')</script>")
+        .instance("/users/\"1\"")
+        .type_("https://example.com/probs/missing?a=1&b=2")
+        .html_page()
+        .to_default_html();
+
+    assert!(html.contains("<h1>&lt;b&gt;Gone&lt;/b&gt;</h1>"));
+    assert!(html.contains("<p>&lt;script&gt;alert(&#39;x&#39;)&lt;/script&gt;</p>"));
+    assert!(html.contains("<code>/users/&quot;1&quot;</code>"));
+    assert!(html.contains(
+        r#"<a href="https://example.com/probs/missing?a=1&amp;b=2">https://example.com/probs/missing?a=1&amp;b=2</a>"#
+    ));
+}
+
+#[test]
+fn does_not_link_to_script_uris() {
+    let problem = ProblemDetails::<()>::new(StatusCode::NOT_FOUND).type_("javascript:alert(1)");
+    let page = problem.html_page();
+
+    assert!(!page.type_is_link);
+    assert!(!page.to_default_html().contains("<a "));
+}
+
+#[test]
+fn renders_validation_errors_table() {
+    let html = ProblemDetails::new(StatusCode::UNPROCESSABLE_ENTITY)
+        .extension(ValidationErrors {
+            errors: vec![
+                ValidationError::new(
+                    "must be > 0",
+                    Source::Body {
+                        pointer: Some(JsonPointer::root().with_key("age")),
+                    },
+                ),
+                ValidationError::new(
+                    "is required",
+                    Source::Header {
+                        name: "X-Color".into(),
+                    },
+                ),
+            ],
+        })
+        .html_page()
+        .to_default_html();
+
+    assert!(
+        html.contains("<tr><td>body</td><td><code>/age</code></td><td>must be &gt; 0</td></tr>")
+    );
+    assert!(
+        html.contains("<tr><td>header</td><td><code>X-Color</code></td><td>is required</td></tr>")
+    );
+}
+
+#[test]
+fn renders_with_custom_template() {
+    fn template(page: &HtmlPage<'_>) -> String {
+        format!("<main>{} ({})</main>", page.title, page.status)
+    }
+    set_html_template(template).unwrap();
+
+    let html = ProblemDetails::<()>::new(StatusCode::NOT_FOUND)
+        .title("Missing <user>")
+        .to_html();
+
+    assert_eq!(html, "<main>Missing &lt;user&gt; (404)</main>");
+}
@@ -0,0 +1,95 @@
+use bytes::Bytes;
+use http::{
+    HeaderName, HeaderValue, StatusCode,
+    header::{
+        CONNECTION, CONTENT_ENCODING, CONTENT_LENGTH, CONTENT_TYPE, RETRY_AFTER, TRANSFER_ENCODING,
+    },
+};
+use krabby_details::{APPLICATION_PROBLEM_JSON, FromResponseError, ProblemDetails};
+use serde_json::{Value, json};
+
+#[test]
+fn http_response_carries_status_headers_and_body() {
+    let problem = ProblemDetails::<()>::new(StatusCode::SERVICE_UNAVAILABLE)
+        .detail("Down for maintenance")
+        .header(RETRY_AFTER, HeaderValue::from_static("120"))
+        .header(
+            HeaderName::from_static("x-request-id"),
+            HeaderValue::from_static("42"),
+        );
+    let response = problem.to_http_response();
+
+    assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(response.headers()[CONTENT_TYPE], APPLICATION_PROBLEM_JSON);
+    assert_eq!(response.headers()[RETRY_AFTER], "120");
+    assert_eq!(response.headers()["x-request-id"], "42");
+    assert_eq!(
+        serde_json::from_slice::<Value>(response.body()).unwrap(),
+        json!({
+            "type": "about:blank",
+            "status": 503,
+            "title": "Service Unavailable",
+            "detail": "Down for maintenance",
+        })
+    );
+
+    // The problem keeps its headers.
+    assert_eq!(problem.headers[RETRY_AFTER], "120");
+}
+
+#[test]
+fn http_response_round_trips() {
+    let problem = ProblemDetails::<()>::new(StatusCode::TOO_MANY_REQUESTS)
+        .detail("Slow down")
+        .header(RETRY_AFTER, HeaderValue::from_static("60"));
+    let response = problem.into_http_response();
+
+    let problem = ProblemDetails::<()>::try_from(response).unwrap();
+    assert_eq!(problem.status, Some(429));
+    assert_eq!(problem.response_status, Some(StatusCode::TOO_MANY_REQUESTS));
+    assert_eq!(problem.detail.as_deref(), Some("Slow down"));
+    assert_eq!(problem.headers.len(), 1);
+    assert_eq!(problem.headers[RETRY_AFTER], "60");
+
+    let response = problem.into_http_response();
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    assert_eq!(response.headers()[RETRY_AFTER], "60");
+}
+
+#[test]
+fn try_from_drops_the_body_headers() {
+    let response = http::Response::builder()
+        .status(StatusCode::BAD_GATEWAY)
+        .header(CONTENT_TYPE, "application/problem+json")
+        .header(CONTENT_LENGTH, "14")
+        .header(CONTENT_ENCODING, "identity")
+        .header(TRANSFER_ENCODING, "chunked")
+        .header(CONNECTION, "close")
+        .header(RETRY_AFTER, "60")
+        .body(Bytes::from_static(br#"{"status":502}"#))
+        .unwrap();
+
+    let problem = ProblemDetails::<()>::try_from(response).unwrap();
+    assert_eq!(problem.headers.len(), 1);
+    assert_eq!(problem.headers[RETRY_AFTER], "60");
+}
+
+#[test]
+fn try_from_rejects_other_content_types() {
+    let response = http::Response::builder()
+        .status(StatusCode::NOT_FOUND)
+        .header(CONTENT_TYPE, "text/plain")
+        .body(Bytes::from_static(b"Not Found"))
+        .unwrap();
+
+    assert!(matches!(
+        ProblemDetails::<()>::try_from(response),
+        Err(FromResponseError::ContentType)
+    ));
+}
+
+#[test]
+fn displays_the_title_and_detail() {
+    let problem = ProblemDetails::<()>::new(StatusCode::NOT_FOUND).detail("No user with id 3");
+    assert_eq!(problem.to_string(), "Not Found: No user with id 3");
+}
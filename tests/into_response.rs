@@ -0,0 +1,94 @@
+#![cfg(feature = "axum")]
+
+use axum_core::response::{IntoResponse, Response};
+use http::{
+    HeaderValue, StatusCode,
+    header::{CONTENT_TYPE, RETRY_AFTER},
+};
+use http_body_util::BodyExt;
+use krabby_details::{APPLICATION_PROBLEM_JSON, ProblemDetails};
+use serde_json::Value;
+
+fn problem(status: u16) -> ProblemDetails<()> {
+    ProblemDetails {
+        type_: "https://example.com/probs/out-of-credit".into(),
+        status: Some(status),
+        title: Some("You do not have enough credit.".into()),
+        detail: Some("Your current balance is 30, but that costs 50.".into()),
+        ..Default::default()
+    }
+}
+
+async fn body_json(response: Response) -> Value {
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    serde_json::from_slice(&bytes).unwrap()
+}
+
+#[tokio::test]
+async fn response_status_matches_body_status() {
+    for status in [400, 403, 404, 409, 422, 429, 500, 502, 503] {
+        let response = problem(status).into_response();
+
+        assert_eq!(response.status().as_u16(), status);
+        assert_eq!(response.headers()[CONTENT_TYPE], APPLICATION_PROBLEM_JSON);
+        assert_eq!(body_json(response).await["status"], status);
+    }
+}
+
+#[tokio::test]
+async fn non_error_status_falls_back_to_internal_server_error() {
+    for status in [0, 99, 200, 204, 301, 1000] {
+        let response = problem(status).into_response();
+
+        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(body_json(response).await["status"], 500);
+    }
+}
+
+#[tokio::test]
+async fn missing_status_falls_back_to_internal_server_error() {
+    let response = ProblemDetails::<()>::default().into_response();
+
+    assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    assert_eq!(
+        body_json(response).await,
+        serde_json::json!({ "type": "about:blank" })
+    );
+}
+
+#[tokio::test]
+async fn builder_sets_members_and_headers() {
+    let response = ProblemDetails::new(StatusCode::TOO_MANY_REQUESTS)
+        .detail("Slow down.")
+        .instance("/requests/42")
+        .extension(serde_json::json!({ "retry_after": 120 }))
+        .header(RETRY_AFTER, HeaderValue::from_static("120"))
+        .into_response();
+
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    assert_eq!(response.headers()[RETRY_AFTER], "120");
+    assert_eq!(
+        body_json(response).await,
+        serde_json::json!({
+            "type": "about:blank",
+            "status": 429,
+            "title": "Too Many Requests",
+            "detail": "Slow down.",
+            "instance": "/requests/42",
+            "retry_after": 120,
+        })
+    );
+}
+
+#[tokio::test]
+async fn response_status_overrides_body_status() {
+    let mut problem = problem(403);
+    problem.response_status = Some(StatusCode::NOT_FOUND);
+
+    assert_eq!(problem.status_code(), StatusCode::NOT_FOUND);
+
+    let response = problem.into_response();
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    assert_eq!(body_json(response).await["status"], 404);
+}
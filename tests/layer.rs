@@ -0,0 +1,108 @@
+#![cfg(feature = "tower")]
+
+use std::convert::Infallible;
+
+use bytes::Bytes;
+use http::{
+    Request, Response, StatusCode,
+    header::{ALLOW, CONTENT_TYPE},
+};
+use http_body_util::{BodyExt, Full};
+use krabby_details::ProblemDetailsLayer;
+use tower::{ServiceBuilder, ServiceExt, service_fn};
+
+async fn call(response: Response<Full<Bytes>>) -> Response<Bytes> {
+    let service = ServiceBuilder::new()
+        .layer(ProblemDetailsLayer::new())
+        .service(service_fn(move |_: Request<()>| {
+            let response = response.clone();
+            async move { Ok::<_, Infallible>(response) }
+        }));
+
+    let response = service.oneshot(Request::new(())).await.unwrap();
+    let (parts, body) = response.into_parts();
+    Response::from_parts(parts, body.collect().await.unwrap().to_bytes())
+}
+
+#[tokio::test]
+async fn rewrites_plain_text_errors_keeping_headers() {
+    let response = call(
+        Response::builder()
+            .status(StatusCode::METHOD_NOT_ALLOWED)
+            .header(CONTENT_TYPE, "text/plain")
+            .header(ALLOW, "GET,HEAD")
+            .body(Full::from("Method Not Allowed"))
+            .unwrap(),
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::METHOD_NOT_ALLOWED);
+    assert_eq!(response.headers()[CONTENT_TYPE], "application/problem+json");
+    assert_eq!(response.headers()[ALLOW], "GET,HEAD");
+    assert_eq!(
+        serde_json::from_slice::<serde_json::Value>(response.body()).unwrap(),
+        serde_json::json!({
+            "type": "about:blank",
+            "status": 405,
+            "title": "Method Not Allowed",
+        })
+    );
+}
+
+#[tokio::test]
+async fn keeps_successes_and_problems() {
+    let ok = call(Response::new(Full::from("hello"))).await;
+    assert_eq!(ok.body(), "hello");
+
+    let problem = call(
+        Response::builder()
+            .status(StatusCode::NOT_FOUND)
+            .header(CONTENT_TYPE, "application/problem+json")
+            .body(Full::from(r#"{"title":"Missing user"}"#))
+            .unwrap(),
+    )
+    .await;
+    assert_eq!(problem.body(), r#"{"title":"Missing user"}"#);
+}
+
+#[tokio::test]
+async fn rewrites_empty_errors() {
+    let response = call(
+        Response::builder()
+            .status(StatusCode::PAYLOAD_TOO_LARGE)
+            .body(Full::default())
+            .unwrap(),
+    )
+    .await;
+
+    assert_eq!(response.headers()[CONTENT_TYPE], "application/problem+json");
+    assert_eq!(
+        serde_json::from_slice::<serde_json::Value>(response.body()).unwrap()["title"],
+        "Payload Too Large"
+    );
+}
+
+#[cfg(feature = "axum")]
+#[tokio::test]
+async fn keeps_negotiated_problems() {
+    use krabby_details::{ProblemDetails, ProblemFormat};
+
+    for format in [ProblemFormat::Html, ProblemFormat::Json] {
+        let service = ServiceBuilder::new()
+            .layer(ProblemDetailsLayer::new())
+            .service(service_fn(move |_: Request<()>| async move {
+                let problem =
+                    ProblemDetails::<()>::new(StatusCode::NOT_FOUND).detail("No user with id 3");
+                Ok::<_, Infallible>(format.respond(problem))
+            }));
+
+        let response = service.oneshot(Request::new(())).await.unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        assert_ne!(response.headers()[CONTENT_TYPE], "application/problem+json");
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        assert!(
+            String::from_utf8_lossy(&body).contains("No user with id 3"),
+            "{format:?} lost the detail"
+        );
+    }
+}
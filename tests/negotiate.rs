@@ -0,0 +1,98 @@
+use http::{HeaderMap, HeaderValue, header::ACCEPT};
+use krabby_details::ProblemFormat;
+
+fn format(accept: &'static str) -> ProblemFormat {
+    let mut headers = HeaderMap::new();
+    headers.insert(ACCEPT, HeaderValue::from_static(accept));
+    ProblemFormat::from_headers(&headers)
+}
+
+#[test]
+fn picks_highest_quality_format() {
+    assert_eq!(
+        format("application/problem+json"),
+        ProblemFormat::ProblemJson
+    );
+    assert_eq!(format("application/json"), ProblemFormat::Json);
+    assert_eq!(
+        format("application/json;q=0.5, application/problem+json"),
+        ProblemFormat::ProblemJson
+    );
+    assert_eq!(
+        format("text/html,application/xhtml+xml,application/xml;q=0.9,*/*;q=0.8"),
+        ProblemFormat::Html
+    );
+    assert_eq!(format("text/html;q=0, */*"), ProblemFormat::ProblemJson);
+    assert_eq!(format("image/png"), ProblemFormat::ProblemJson);
+    assert_eq!(
+        ProblemFormat::from_headers(&HeaderMap::new()),
+        ProblemFormat::ProblemJson
+    );
+}
+
+#[test]
+fn wildcards_do_not_pick_excluded_formats() {
+    assert_eq!(
+        format("application/problem+json;q=0, application/json;q=0.5, */*;q=0.9"),
+        ProblemFormat::Json
+    );
+    assert_eq!(
+        format("application/problem+json;q=0, application/*, text/html;q=0.1"),
+        ProblemFormat::Html
+    );
+    assert_eq!(
+        format("text/html;q=0, text/*, application/json;q=0.2"),
+        ProblemFormat::Json
+    );
+}
+
+#[cfg(feature = "xml")]
+#[test]
+fn picks_xml_when_enabled() {
+    assert_eq!(
+        format("application/problem+xml, application/problem+json;q=0.9"),
+        ProblemFormat::ProblemXml
+    );
+}
+
+#[cfg(not(feature = "xml"))]
+#[test]
+fn skips_xml_when_disabled() {
+    assert_eq!(
+        format("application/problem+xml, application/json;q=0.5"),
+        ProblemFormat::Json
+    );
+}
+
+#[cfg(feature = "axum")]
+#[test]
+fn responds_in_the_negotiated_format() {
+    use http::{
+        StatusCode,
+        header::{CONTENT_TYPE, VARY},
+    };
+    use krabby_details::ProblemDetails;
+
+    let problem = || ProblemDetails::<()>::new(StatusCode::NOT_FOUND).detail("<none>");
+
+    let response = ProblemFormat::Json.respond(problem());
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    assert_eq!(response.headers()[CONTENT_TYPE], "application/json");
+    assert_eq!(response.headers()[VARY], "accept");
+
+    let response = ProblemFormat::Html.respond(problem());
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    assert_eq!(response.headers()[CONTENT_TYPE], "text/html; charset=utf-8");
+    assert_eq!(response.headers()[VARY], "accept");
+}
+
+#[cfg(all(feature = "axum", not(feature = "xml")))]
+#[test]
+fn responds_with_json_for_xml_when_disabled() {
+    use http::{StatusCode, header::CONTENT_TYPE};
+    use krabby_details::ProblemDetails;
+
+    let response =
+        ProblemFormat::ProblemXml.respond(ProblemDetails::<()>::new(StatusCode::NOT_FOUND));
+    assert_eq!(response.headers()[CONTENT_TYPE], "application/problem+json");
+}
@@ -0,0 +1,31 @@
+#![cfg(feature = "poem")]
+
+use http::{HeaderValue, StatusCode, header::RETRY_AFTER};
+use krabby_details::ProblemDetails;
+use poem::IntoResponse;
+
+#[tokio::test]
+async fn responds_like_the_axum_integration() {
+    let problem = ProblemDetails::<()>::new(StatusCode::SERVICE_UNAVAILABLE)
+        .header(RETRY_AFTER, HeaderValue::from_static("120"));
+    let expected = problem.to_http_response();
+
+    let response = IntoResponse::into_response(problem);
+    assert_eq!(response.status(), expected.status());
+    assert_eq!(response.headers(), expected.headers());
+    let body = response.into_body().into_bytes().await.unwrap();
+    assert_eq!(body, expected.into_body());
+}
+
+#[tokio::test]
+async fn handlers_can_fail_with_a_problem() {
+    let problem = ProblemDetails::<()>::new(StatusCode::NOT_FOUND).detail("No user with id 3");
+    let expected = problem.to_http_response();
+
+    let error = poem::Error::from(problem);
+    assert_eq!(error.status(), StatusCode::NOT_FOUND);
+    let response = error.into_response();
+    assert_eq!(response.headers(), expected.headers());
+    let body = response.into_body().into_bytes().await.unwrap();
+    assert_eq!(body, expected.into_body());
+}
@@ -0,0 +1,45 @@
+use http::StatusCode;
+use krabby_details::{DuplicateProblemType, ProblemDetails, ProblemType, ProblemTypeRegistry};
+
+const OUT_OF_CREDIT: ProblemType = ProblemType::new(
+    "https://example.com/probs/out-of-credit",
+    "You do not have enough credit.",
+    StatusCode::FORBIDDEN,
+);
+
+const NOT_FOUND: ProblemType = ProblemType::new(
+    "https://example.com/probs/not-found",
+    "The resource does not exist.",
+    StatusCode::NOT_FOUND,
+)
+.description("No resource matches the requested identifier.");
+
+#[test]
+fn registry_rejects_duplicate_uris() {
+    let mut registry = ProblemTypeRegistry::try_from(vec![OUT_OF_CREDIT, NOT_FOUND]).unwrap();
+
+    assert_eq!(
+        registry.register(ProblemType::new(
+            "https://example.com/probs/not-found",
+            "Missing",
+            StatusCode::GONE,
+        )),
+        Err(DuplicateProblemType {
+            uri: "https://example.com/probs/not-found"
+        })
+    );
+    assert_eq!(
+        registry.iter().collect::<Vec<_>>(),
+        [&OUT_OF_CREDIT, &NOT_FOUND]
+    );
+    assert_eq!(registry.get(NOT_FOUND.uri), Some(&NOT_FOUND));
+}
+
+#[test]
+fn problem_from_type_uses_its_defaults() {
+    let problem = ProblemDetails::<()>::from_type(&OUT_OF_CREDIT).detail("Your balance is 30.");
+
+    assert_eq!(problem.type_, OUT_OF_CREDIT.uri);
+    assert_eq!(problem.status, Some(403));
+    assert_eq!(problem.title.as_deref(), Some(OUT_OF_CREDIT.title));
+}
@@ -0,0 +1,147 @@
+use http::StatusCode;
+use krabby_details::{ProblemDetails, ReservedMemberPolicy};
+use serde_json::json;
+
+#[derive(serde::Serialize)]
+struct Colliding {
+    status: &'static str,
+    balance: u32,
+}
+
+fn problem(policy: ReservedMemberPolicy) -> ProblemDetails<Colliding> {
+    ProblemDetails::new(StatusCode::FORBIDDEN)
+        .extension(Colliding {
+            status: "suspended",
+            balance: 30,
+        })
+        .reserved_members(policy)
+}
+
+#[test]
+fn reject_fails_serialization() {
+    let error = serde_json::to_vec(&problem(ReservedMemberPolicy::Reject)).unwrap_err();
+
+    assert!(error.to_string().contains("`status`"));
+}
+
+#[test]
+fn prefix_renames_colliding_members() {
+    assert_eq!(
+        serde_json::to_value(problem(ReservedMemberPolicy::Prefix("account_"))).unwrap(),
+        json!({
+            "type": "about:blank",
+            "status": 403,
+            "title": "Forbidden",
+            "account_status": "suspended",
+            "balance": 30,
+        })
+    );
+}
+
+#[test]
+fn drop_leaves_colliding_members_out() {
+    assert_eq!(
+        serde_json::to_value(problem(ReservedMemberPolicy::Drop)).unwrap(),
+        json!({
+            "type": "about:blank",
+            "status": 403,
+            "title": "Forbidden",
+            "balance": 30,
+        })
+    );
+}
+
+#[test]
+fn map_extensions_follow_the_policy() {
+    let mut problem = ProblemDetails::new(StatusCode::FORBIDDEN)
+        .reserved_members(ReservedMemberPolicy::Prefix("ext_"));
+    problem.insert_extension("title", "Account suspended");
+    problem.insert_extension("trace_id", "4bf92f3577b34da6");
+
+    assert_eq!(
+        serde_json::to_value(&problem).unwrap(),
+        json!({
+            "type": "about:blank",
+            "status": 403,
+            "title": "Forbidden",
+            "ext_title": "Account suspended",
+            "trace_id": "4bf92f3577b34da6",
+        })
+    );
+}
+
+#[test]
+fn non_colliding_members_keep_their_order() {
+    let problem = ProblemDetails::new(StatusCode::FORBIDDEN).extension(json!({ "balance": 30 }));
+
+    assert_eq!(
+        serde_json::to_string(&problem).unwrap(),
+        r#"{"type":"about:blank","status":403,"title":"Forbidden","balance":30}"#
+    );
+}
+
+#[derive(serde::Serialize)]
+enum Account {
+    Active,
+    Suspended(&'static str),
+    Closed { status: &'static str, at: u32 },
+    Frozen(u32, u32),
+}
+
+fn enum_problem(account: Account) -> String {
+    serde_json::to_string(&ProblemDetails::new(StatusCode::FORBIDDEN).extension(account)).unwrap()
+}
+
+#[test]
+fn enum_extensions_are_externally_tagged() {
+    let prefix = r#"{"type":"about:blank","status":403,"title":"Forbidden","#;
+
+    assert_eq!(
+        enum_problem(Account::Active),
+        format!(r#"{prefix}"Active":null}}"#)
+    );
+    assert_eq!(
+        enum_problem(Account::Suspended("fraud")),
+        format!(r#"{prefix}"Suspended":"fraud"}}"#)
+    );
+    assert_eq!(
+        enum_problem(Account::Closed {
+            status: "final",
+            at: 7
+        }),
+        format!(r#"{prefix}"Closed":{{"status":"final","at":7}}}}"#)
+    );
+    assert_eq!(
+        enum_problem(Account::Frozen(1, 2)),
+        format!(r#"{prefix}"Frozen":[1,2]}}"#)
+    );
+}
+
+#[derive(serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+enum Reserved {
+    Status(u16),
+    Title { text: &'static str },
+}
+
+#[test]
+fn enum_variant_names_follow_the_policy() {
+    let problem = ProblemDetails::new(StatusCode::FORBIDDEN).extension(Reserved::Status(1));
+    assert!(serde_json::to_vec(&problem).is_err());
+
+    let problem = ProblemDetails::new(StatusCode::FORBIDDEN)
+        .extension(Reserved::Title { text: "x" })
+        .reserved_members(ReservedMemberPolicy::Prefix("ext_"));
+    assert_eq!(
+        serde_json::to_value(problem).unwrap()["ext_title"],
+        json!({ "text": "x" })
+    );
+
+    let problem = ProblemDetails::new(StatusCode::FORBIDDEN)
+        .extension(Reserved::Status(1))
+        .reserved_members(ReservedMemberPolicy::Drop);
+    assert_eq!(
+        serde_json::to_value(problem).unwrap(),
+        json!({ "type": "about:blank", "status": 403, "title": "Forbidden" })
+    );
+}
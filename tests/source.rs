@@ -0,0 +1,119 @@
+use krabby_details::{
+    JsonPointer, ParseJsonPointerError, Source, ValidationError, ValidationErrors,
+};
+use serde_json::json;
+
+#[test]
+fn sources_serialize_flattened_with_their_tag() {
+    let errors = [
+        (
+            Source::Query {
+                parameter: Some("page".into()),
+            },
+            json!({ "detail": "invalid", "source": "query", "parameter": "page" }),
+        ),
+        (
+            Source::Path {
+                parameter: Some("id".into()),
+            },
+            json!({ "detail": "invalid", "source": "path", "parameter": "id" }),
+        ),
+        (
+            Source::Cookie {
+                name: "session".into(),
+            },
+            json!({ "detail": "invalid", "source": "cookie", "name": "session" }),
+        ),
+        (
+            Source::Form {
+                field: Some("email".into()),
+            },
+            json!({ "detail": "invalid", "source": "form", "field": "email" }),
+        ),
+        (
+            Source::Multipart {
+                part: "metadata".into(),
+                pointer: Some(JsonPointer::root().with_key("title")),
+            },
+            json!({ "detail": "invalid", "source": "multipart", "part": "metadata", "pointer": "/title" }),
+        ),
+    ];
+
+    for (source, expected) in errors {
+        let error = ValidationError::new("invalid", source);
+        let value = serde_json::to_value(&error).unwrap();
+        assert_eq!(value, expected);
+
+        let error: ValidationError = serde_json::from_value(value).unwrap();
+        assert_eq!(serde_json::to_value(&error).unwrap(), expected);
+    }
+}
+
+#[test]
+fn constraint_details_serialize_alongside_the_source() {
+    let error = ValidationError::new(
+        "too short",
+        Source::Body {
+            pointer: Some(JsonPointer::root().with_key("password")),
+        },
+    )
+    .code("too_short")
+    .param("min", 8)
+    .rejected_value("hunter2");
+    let mut errors = ValidationErrors {
+        errors: vec![error],
+    };
+
+    assert_eq!(
+        serde_json::to_value(&errors).unwrap(),
+        json!({
+            "errors": [{
+                "detail": "too short",
+                "code": "too_short",
+                "params": { "min": 8 },
+                "rejected_value": "hunter2",
+                "source": "body",
+                "pointer": "/password",
+            }]
+        })
+    );
+
+    errors.redact_rejected_values();
+    assert_eq!(
+        serde_json::to_value(&errors).unwrap()["errors"][0].get("rejected_value"),
+        None
+    );
+}
+
+#[test]
+fn json_pointer_escapes_parses_and_resolves() {
+    let pointer = JsonPointer::root()
+        .with_key("m~n")
+        .with_key("a/b")
+        .with_index(1);
+    let document = json!({ "m~n": { "a/b": [10, 20] } });
+
+    assert_eq!(pointer.to_string(), "/m~0n/a~1b/1");
+    assert_eq!("/m~0n/a~1b/1".parse::<JsonPointer>(), Ok(pointer.clone()));
+    assert_eq!(pointer.resolve(&document), Some(&json!(20)));
+    assert_eq!(JsonPointer::root().resolve(&document), Some(&document));
+    assert_eq!(
+        "/m~0n/a~1b/01"
+            .parse::<JsonPointer>()
+            .unwrap()
+            .resolve(&document),
+        None
+    );
+    assert_eq!(
+        "m".parse::<JsonPointer>(),
+        Err(ParseJsonPointerError::MissingSlash)
+    );
+    assert_eq!(
+        "/m~2".parse::<JsonPointer>(),
+        Err(ParseJsonPointerError::InvalidEscape)
+    );
+    assert_eq!(
+        serde_json::to_value(&pointer).unwrap(),
+        json!("/m~0n/a~1b/1")
+    );
+}
@@ -0,0 +1,12 @@
+use krabby_details::Problem;
+
+#[derive(Problem)]
+enum AccountError {
+    #[problem(status = 429)]
+    RateLimited {
+        #[problem(extension)]
+        status: u16,
+    },
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use krabby_details::Problem;
+
+#[derive(Problem)]
+enum AccountError {
+    #[problem(status = 404)]
+    NotFound(#[problem(extension = "title")] String),
+}
+
+fn main() {}
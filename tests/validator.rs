@@ -0,0 +1,94 @@
+#![cfg(feature = "validator")]
+
+use krabby_details::{ProblemDetails, ValidationErrors};
+use serde_json::json;
+use validator::Validate;
+
+#[derive(Validate)]
+struct SignUp {
+    #[validate(length(min = 3))]
+    name: String,
+    #[validate(nested)]
+    address: Address,
+    #[validate(nested)]
+    pets: Vec<Pet>,
+}
+
+#[derive(Validate)]
+struct Address {
+    #[validate(length(min = 1, message = "the city is required"))]
+    city: String,
+}
+
+#[derive(Validate)]
+struct Pet {
+    #[validate(range(max = 30))]
+    age: u32,
+}
+
+fn invalid_sign_up() -> validator::ValidationErrors {
+    let sign_up = SignUp {
+        name: "Fe".into(),
+        address: Address {
+            city: String::new(),
+        },
+        pets: vec![Pet { age: 3 }, Pet { age: 42 }],
+    };
+    sign_up.validate().unwrap_err()
+}
+
+#[test]
+fn walks_nested_struct_and_list_errors() {
+    let problem = ProblemDetails::<ValidationErrors>::from(invalid_sign_up());
+
+    assert_eq!(problem.status, Some(422));
+    assert_eq!(
+        serde_json::to_value(problem.extensions.unwrap()).unwrap(),
+        json!({
+            "errors": [
+                {
+                    "detail": "the city is required",
+                    "code": "length",
+                    "params": { "min": 1 },
+                    "source": "body",
+                    "pointer": "/address/city",
+                },
+                {
+                    "detail": "failed the `length` validation",
+                    "code": "length",
+                    "params": { "min": 3 },
+                    "source": "body",
+                    "pointer": "/name",
+                },
+                {
+                    "detail": "failed the `range` validation",
+                    "code": "range",
+                    "params": { "max": 30 },
+                    "source": "body",
+                    "pointer": "/pets/1/age",
+                },
+            ]
+        })
+    );
+}
+
+#[test]
+fn keeps_rejected_values_on_request() {
+    let errors = ValidationErrors::with_rejected_values(invalid_sign_up());
+
+    let rejected_values = errors
+        .errors
+        .iter()
+        .map(|error| error.rejected_value.clone())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        rejected_values,
+        [Some(json!("")), Some(json!("Fe")), Some(json!(42))]
+    );
+    assert!(
+        errors
+            .errors
+            .iter()
+            .all(|error| !error.params.contains_key("value"))
+    );
+}
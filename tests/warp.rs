@@ -0,0 +1,55 @@
+#![cfg(feature = "warp")]
+
+use http::{StatusCode, header::CONTENT_TYPE};
+use http_body_util::BodyExt;
+use krabby_details::{APPLICATION_PROBLEM_JSON, ProblemDetails, recover_problem};
+use serde_json::{Value, json};
+use warp::{
+    Reply,
+    reject::{self, Reject},
+    reply::Response,
+};
+
+async fn body_json(response: Response) -> Value {
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    serde_json::from_slice(&body).unwrap()
+}
+
+#[tokio::test]
+async fn replies_like_the_axum_integration() {
+    let problem = ProblemDetails::<()>::new(StatusCode::CONFLICT).detail("Already taken");
+    let expected = problem.to_http_response();
+
+    let response = Reply::into_response(problem);
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+    assert_eq!(response.headers()[CONTENT_TYPE], APPLICATION_PROBLEM_JSON);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    assert_eq!(body, expected.into_body());
+}
+
+#[derive(Debug)]
+struct Unauthorized;
+
+impl Reject for Unauthorized {}
+
+#[tokio::test]
+async fn recovers_from_problem_rejections() {
+    let problem = ProblemDetails::<()>::new(StatusCode::FORBIDDEN);
+    let response = recover_problem::<()>(reject::custom(problem))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    assert_eq!(body_json(response).await["title"], "Forbidden");
+
+    let response = recover_problem::<()>(reject::not_found()).await.unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    assert_eq!(
+        body_json(response).await,
+        json!({ "type": "about:blank", "status": 404, "title": "Not Found" })
+    );
+
+    let rejection = recover_problem::<()>(reject::custom(Unauthorized))
+        .await
+        .unwrap_err();
+    assert!(rejection.find::<Unauthorized>().is_some());
+}
@@ -0,0 +1,75 @@
+#![cfg(feature = "xml")]
+
+use http::StatusCode;
+use krabby_details::{
+    JsonPointer, ProblemDetails, Source, ValidationError, ValidationErrors, XmlError,
+};
+
+#[test]
+fn writes_standard_members_then_extensions() {
+    let mut problem = ProblemDetails::new(StatusCode::FORBIDDEN)
+        .type_("https://example.com/probs/out-of-credit")
+        .title("You do not have enough credit.")
+        .detail("Your current balance is 30, but that costs 50 & more.")
+        .instance("https://example.net/account/12345/msgs/abc");
+    problem.insert_extension("balance", 30);
+    problem.insert_extension("accounts", vec!["/account/12345", "/account/67890"]);
+
+    assert_eq!(
+        problem.to_xml().unwrap(),
+        concat!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>"#,
+            r#"<problem xmlns="urn:ietf:rfc:7807">"#,
+            "<type>https://example.com/probs/out-of-credit</type>",
+            "<status>403</status>",
+            "<title>You do not have enough credit.</title>",
+            "<detail>Your current balance is 30, but that costs 50 &amp; more.</detail>",
+            "<instance>https://example.net/account/12345/msgs/abc</instance>",
+            "<accounts><i>/account/12345</i><i>/account/67890</i></accounts>",
+            "<balance>30</balance>",
+            "</problem>",
+        )
+    );
+}
+
+#[test]
+fn writes_validation_errors() {
+    let problem =
+        ProblemDetails::new(StatusCode::UNPROCESSABLE_ENTITY).extension(ValidationErrors {
+            errors: vec![ValidationError::new(
+                "must be a positive integer",
+                Source::Body {
+                    pointer: Some(JsonPointer::root().with_key("age")),
+                },
+            )],
+        });
+
+    assert!(problem.to_xml().unwrap().ends_with(concat!(
+        "<errors><i>",
+        "<detail>must be a positive integer</detail>",
+        "<pointer>/age</pointer>",
+        "<source>body</source>",
+        "</i></errors>",
+        "</problem>",
+    )));
+}
+
+#[test]
+fn rejects_invalid_element_names() {
+    let mut problem = ProblemDetails::new(StatusCode::FORBIDDEN);
+    problem.insert_extension("1st", true);
+
+    assert!(matches!(problem.to_xml(), Err(XmlError::InvalidName(name)) if name == "1st"));
+}
+
+#[test]
+fn rejects_characters_xml_does_not_allow() {
+    let problem = ProblemDetails::<()>::new(StatusCode::BAD_REQUEST).detail("bad \u{1} byte");
+    assert!(matches!(
+        problem.to_xml(),
+        Err(XmlError::InvalidChar('\u{1}'))
+    ));
+
+    let problem = ProblemDetails::<()>::new(StatusCode::BAD_REQUEST).detail("tab\tand\nnewline");
+    assert!(problem.to_xml().is_ok());
+}